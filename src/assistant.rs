@@ -0,0 +1,73 @@
+use serde::Serialize;
+
+use crate::client::SlackClient;
+use crate::error::SlackApiError;
+
+/// A prompt button shown in an assistant thread. Clicking it sends `message` as the user.
+#[derive(Serialize, Debug, Clone)]
+pub struct SuggestedPrompt {
+    pub title: String,
+    pub message: String,
+}
+
+impl SlackClient {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_assistant_thread_title(
+        &self,
+        channel_id: &str,
+        thread_ts: &str,
+        title: &str,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "assistant.threads.setTitle",
+            &serde_json::json!({
+                "channel_id": channel_id,
+                "thread_ts": thread_ts,
+                "title": title,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Shows a status such as "is thinking..." under the thread. An empty `status` clears it;
+    /// posting a reply in the thread clears it too.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_assistant_thread_status(
+        &self,
+        channel_id: &str,
+        thread_ts: &str,
+        status: &str,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "assistant.threads.setStatus",
+            &serde_json::json!({
+                "channel_id": channel_id,
+                "thread_ts": thread_ts,
+                "status": status,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Replaces the thread's suggested prompts. Slack shows at most four.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_assistant_suggested_prompts(
+        &self,
+        channel_id: &str,
+        thread_ts: &str,
+        prompts: &[SuggestedPrompt],
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "assistant.threads.setSuggestedPrompts",
+            &serde_json::json!({
+                "channel_id": channel_id,
+                "thread_ts": thread_ts,
+                "prompts": prompts,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+}
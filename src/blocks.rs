@@ -0,0 +1,2421 @@
+//! Block Kit building blocks for messages and views.
+//!
+//! Blocks and elements serialize to the JSON Slack expects, `"type"` field included, and leave
+//! out optional fields that are `None`.
+
+use serde::Serialize;
+
+/// A block that breaks one of Slack's Block Kit limits, caught before it's sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockError {
+    TooManyElements {
+        block: &'static str,
+        max: usize,
+    },
+    TooFewElements {
+        block: &'static str,
+        min: usize,
+    },
+    /// A multi-select's `max_selected_items` was 0.
+    InvalidMaxSelectedItems,
+    /// A file input's `max_files` was outside 1 to 10.
+    InvalidMaxFiles,
+    /// `max` is in characters, not bytes.
+    TextTooLong {
+        field: &'static str,
+        max: usize,
+    },
+}
+
+impl std::fmt::Display for BlockError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyElements { block, max } => {
+                write!(f, "{} holds at most {} elements", block, max)
+            }
+            Self::TooFewElements { block, min } => {
+                write!(f, "{} needs at least {} elements", block, min)
+            }
+            Self::InvalidMaxSelectedItems => write!(f, "max_selected_items must be at least 1"),
+            Self::InvalidMaxFiles => write!(f, "max_files must be between 1 and 10"),
+            Self::TextTooLong { field, max } => {
+                write!(f, "{} is longer than {} characters", field, max)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BlockError {}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename = "plain_text")]
+pub struct PlainTextObject {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<bool>,
+}
+
+impl PlainTextObject {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type", rename = "mrkdwn")]
+pub struct MrkdwnObject {
+    pub text: String,
+    /// Skips Slack's automatic linking of URLs, channel names and mentions.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verbatim: Option<bool>,
+}
+
+impl MrkdwnObject {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(untagged)]
+pub enum TextObject {
+    PlainText(PlainTextObject),
+    Mrkdwn(MrkdwnObject),
+}
+
+impl TextObject {
+    pub fn plain(text: impl Into<String>) -> Self {
+        Self::PlainText(PlainTextObject::new(text))
+    }
+
+    pub fn mrkdwn(text: impl Into<String>) -> Self {
+        Self::Mrkdwn(MrkdwnObject::new(text))
+    }
+}
+
+impl From<PlainTextObject> for TextObject {
+    fn from(text: PlainTextObject) -> Self {
+        Self::PlainText(text)
+    }
+}
+
+impl From<MrkdwnObject> for TextObject {
+    fn from(text: MrkdwnObject) -> Self {
+        Self::Mrkdwn(text)
+    }
+}
+
+/// An entry in a select menu or overflow menu.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OptionObject {
+    pub text: PlainTextObject,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<PlainTextObject>,
+}
+
+impl OptionObject {
+    pub fn new(text: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            text: PlainTextObject::new(text),
+            value: value.into(),
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(PlainTextObject::new(description));
+        self
+    }
+}
+
+/// A labelled group of options in a select menu.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OptionGroup {
+    pub label: PlainTextObject,
+    pub options: Vec<OptionObject>,
+}
+
+impl OptionGroup {
+    pub fn new(label: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Self {
+            label: PlainTextObject::new(label),
+            options,
+        }
+    }
+}
+
+/// An interactive or image element that can sit inside a block, e.g. as a section's accessory.
+pub trait BlockElement: std::fmt::Debug + Send + Sync {
+    fn to_value(&self) -> serde_json::Value;
+}
+
+impl Serialize for dyn BlockElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_value().serialize(serializer)
+    }
+}
+
+macro_rules! impl_block_element {
+    ($($element:ty),* $(,)?) => {
+        $(
+            impl BlockElement for $element {
+                fn to_value(&self) -> serde_json::Value {
+                    serde_json::to_value(self).expect("Block Kit elements always serialize")
+                }
+            }
+        )*
+    };
+}
+
+/// An element allowed in an [`ActionsBlock`].
+pub trait ActionElement: BlockElement {}
+
+impl Serialize for dyn ActionElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_value().serialize(serializer)
+    }
+}
+
+/// An element allowed in an [`InputBlock`].
+pub trait InputElement: BlockElement {}
+
+impl Serialize for dyn InputElement {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_value().serialize(serializer)
+    }
+}
+
+macro_rules! impl_marker_trait {
+    ($marker:ident for $($element:ty),* $(,)?) => {
+        $(impl $marker for $element {})*
+    };
+}
+
+impl_marker_trait!(
+    ActionElement for ButtonElement,
+    OverflowMenuElement,
+    StaticSelectElement,
+    ExternalSelectElement,
+    DatePickerElement,
+    TimePickerElement,
+    RadioButtonGroupElement,
+    CheckboxGroupElement,
+    MultiStaticSelectElement,
+    MultiExternalSelectElement,
+    UsersSelectElement,
+    MultiUsersSelectElement,
+    ConversationsSelectElement,
+    ChannelsSelectElement,
+);
+
+impl_marker_trait!(
+    InputElement for PlainTextInputElement,
+    DatePickerElement,
+    TimePickerElement,
+    RadioButtonGroupElement,
+    CheckboxGroupElement,
+    StaticSelectElement,
+    ExternalSelectElement,
+    MultiStaticSelectElement,
+    MultiExternalSelectElement,
+    UsersSelectElement,
+    MultiUsersSelectElement,
+    ConversationsSelectElement,
+    ChannelsSelectElement,
+    NumberInputElement,
+    UrlInputElement,
+    EmailInputElement,
+    FileInputElement,
+);
+
+impl_block_element!(
+    ButtonElement,
+    ImageElement,
+    OverflowMenuElement,
+    StaticSelectElement,
+    ExternalSelectElement,
+    DatePickerElement,
+    TimePickerElement,
+    PlainTextInputElement,
+    RadioButtonGroupElement,
+    CheckboxGroupElement,
+    MultiStaticSelectElement,
+    MultiExternalSelectElement,
+    UsersSelectElement,
+    MultiUsersSelectElement,
+    ConversationsSelectElement,
+    ChannelsSelectElement,
+    NumberInputElement,
+    UrlInputElement,
+    EmailInputElement,
+    FileInputElement,
+);
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    /// Slack's neutral style; serialized by leaving `style` out.
+    #[default]
+    Default,
+    Primary,
+    Danger,
+}
+
+fn is_default_style(style: &Option<ButtonStyle>) -> bool {
+    matches!(style, None | Some(ButtonStyle::Default))
+}
+
+/// Asks the user to confirm before an element's action is sent.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ConfirmDialog {
+    pub title: PlainTextObject,
+    pub text: TextObject,
+    /// Label of the confirm button.
+    pub confirm: PlainTextObject,
+    /// Label of the cancel button.
+    pub deny: PlainTextObject,
+    #[serde(skip_serializing_if = "is_default_style")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl ConfirmDialog {
+    pub fn new(
+        title: impl Into<String>,
+        text: impl Into<TextObject>,
+        confirm: impl Into<String>,
+        deny: impl Into<String>,
+    ) -> Self {
+        Self {
+            title: PlainTextObject::new(title),
+            text: text.into(),
+            confirm: PlainTextObject::new(confirm),
+            deny: PlainTextObject::new(deny),
+            style: None,
+        }
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "button")]
+pub struct ButtonElement {
+    pub text: PlainTextObject,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "is_default_style")]
+    pub style: Option<ButtonStyle>,
+    /// Opens this URL in the user's browser. The click is still sent to the app.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl ButtonElement {
+    pub fn new(text: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            text: PlainTextObject::new(text),
+            action_id: action_id.into(),
+            value: None,
+            style: None,
+            url: None,
+            confirm: None,
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    pub fn style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A small image, for a section's accessory or a context block. See [`ImageBlock`] for a
+/// full-width one.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "image")]
+pub struct ImageElement {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+impl ImageElement {
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Self {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "overflow")]
+pub struct OverflowMenuElement {
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+/// Slack requires an overflow menu to have 2 to 5 options.
+const MIN_OVERFLOW_OPTIONS: usize = 2;
+const MAX_OVERFLOW_OPTIONS: usize = 5;
+
+impl OverflowMenuElement {
+    /// Enforces the option limits: `add_option` fails past 5, `build` fails below 2.
+    pub fn builder(action_id: impl Into<String>) -> OverflowMenuBuilder {
+        OverflowMenuBuilder {
+            action_id: action_id.into(),
+            options: Vec::new(),
+            confirm: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OverflowMenuBuilder {
+    action_id: String,
+    options: Vec<OptionObject>,
+    confirm: Option<ConfirmDialog>,
+}
+
+impl OverflowMenuBuilder {
+    pub fn add_option(mut self, option: OptionObject) -> Result<Self, BlockError> {
+        if self.options.len() >= MAX_OVERFLOW_OPTIONS {
+            return Err(BlockError::TooManyElements {
+                block: "overflow menu",
+                max: MAX_OVERFLOW_OPTIONS,
+            });
+        }
+        self.options.push(option);
+        Ok(self)
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn build(self) -> Result<OverflowMenuElement, BlockError> {
+        if self.options.len() < MIN_OVERFLOW_OPTIONS {
+            return Err(BlockError::TooFewElements {
+                block: "overflow menu",
+                min: MIN_OVERFLOW_OPTIONS,
+            });
+        }
+        Ok(OverflowMenuElement {
+            action_id: self.action_id,
+            options: self.options,
+            confirm: self.confirm,
+        })
+    }
+}
+
+/// A select menu with a fixed list of options. Set either `options` or `option_groups`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "static_select")]
+pub struct StaticSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl StaticSelectElement {
+    pub fn new(
+        placeholder: impl Into<String>,
+        action_id: impl Into<String>,
+        options: Vec<OptionObject>,
+    ) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            options,
+            option_groups: None,
+            initial_option: None,
+            confirm: None,
+        }
+    }
+
+    /// Shows the options in labelled groups instead of one flat list.
+    pub fn option_groups(mut self, option_groups: Vec<OptionGroup>) -> Self {
+        self.options.clear();
+        self.option_groups = Some(option_groups);
+        self
+    }
+
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A select menu whose options are loaded from the app's options load URL as the user types.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "external_select")]
+pub struct ExternalSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    /// Characters typed before options are requested. Slack defaults to 3.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl ExternalSelectElement {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            initial_option: None,
+            min_query_length: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+
+    pub fn min_query_length(mut self, min_query_length: u32) -> Self {
+        self.min_query_length = Some(min_query_length);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A select menu that takes several choices from a fixed list. Set either `options` or
+/// `option_groups`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "multi_static_select")]
+pub struct MultiStaticSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub option_groups: Option<Vec<OptionGroup>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+}
+
+impl MultiStaticSelectElement {
+    pub fn new(
+        placeholder: impl Into<String>,
+        action_id: impl Into<String>,
+        options: Vec<OptionObject>,
+    ) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            options,
+            option_groups: None,
+            initial_options: None,
+            confirm: None,
+            max_selected_items: None,
+        }
+    }
+
+    /// Shows the options in labelled groups instead of one flat list.
+    pub fn option_groups(mut self, option_groups: Vec<OptionGroup>) -> Self {
+        self.options.clear();
+        self.option_groups = Some(option_groups);
+        self
+    }
+
+    pub fn initial_options(mut self, initial_options: Vec<OptionObject>) -> Self {
+        self.initial_options = Some(initial_options);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Fails with [`BlockError::InvalidMaxSelectedItems`] for 0.
+    pub fn max_selected_items(mut self, max: u32) -> Result<Self, BlockError> {
+        if max == 0 {
+            return Err(BlockError::InvalidMaxSelectedItems);
+        }
+        self.max_selected_items = Some(max);
+        Ok(self)
+    }
+}
+
+/// Like [`ExternalSelectElement`], but takes several choices.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "multi_external_select")]
+pub struct MultiExternalSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_query_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+}
+
+impl MultiExternalSelectElement {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            initial_options: None,
+            min_query_length: None,
+            confirm: None,
+            max_selected_items: None,
+        }
+    }
+
+    pub fn initial_options(mut self, initial_options: Vec<OptionObject>) -> Self {
+        self.initial_options = Some(initial_options);
+        self
+    }
+
+    pub fn min_query_length(mut self, min_query_length: u32) -> Self {
+        self.min_query_length = Some(min_query_length);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Fails with [`BlockError::InvalidMaxSelectedItems`] for 0.
+    pub fn max_selected_items(mut self, max: u32) -> Result<Self, BlockError> {
+        if max == 0 {
+            return Err(BlockError::InvalidMaxSelectedItems);
+        }
+        self.max_selected_items = Some(max);
+        Ok(self)
+    }
+}
+
+/// A menu of the workspace's users, filled in by Slack.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "users_select")]
+pub struct UsersSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    /// A user ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl UsersSelectElement {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            initial_user: None,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_user(mut self, initial_user: impl Into<String>) -> Self {
+        self.initial_user = Some(initial_user.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "multi_users_select")]
+pub struct MultiUsersSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_selected_items: Option<u32>,
+}
+
+impl MultiUsersSelectElement {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            initial_users: None,
+            confirm: None,
+            max_selected_items: None,
+        }
+    }
+
+    pub fn initial_users(mut self, initial_users: Vec<String>) -> Self {
+        self.initial_users = Some(initial_users);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    /// Fails with [`BlockError::InvalidMaxSelectedItems`] for 0.
+    pub fn max_selected_items(mut self, max: u32) -> Result<Self, BlockError> {
+        if max == 0 {
+            return Err(BlockError::InvalidMaxSelectedItems);
+        }
+        self.max_selected_items = Some(max);
+        Ok(self)
+    }
+}
+
+/// A menu of conversations the user can see: channels, DMs and group DMs.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "conversations_select")]
+pub struct ConversationsSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    /// A conversation ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_conversation: Option<String>,
+    /// Preselects the conversation the view was opened from. Ignored if `initial_conversation`
+    /// is set.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub default_to_current_conversation: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<ConversationFilter>,
+    /// Includes a `response_url` in the view submission, for posting to the chosen channel.
+    /// Only works in an input block of a modal.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub response_url_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl ConversationsSelectElement {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            initial_conversation: None,
+            default_to_current_conversation: false,
+            filter: None,
+            response_url_enabled: false,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_conversation(mut self, initial_conversation: impl Into<String>) -> Self {
+        self.initial_conversation = Some(initial_conversation.into());
+        self
+    }
+
+    pub fn default_to_current_conversation(
+        mut self,
+        default_to_current_conversation: bool,
+    ) -> Self {
+        self.default_to_current_conversation = default_to_current_conversation;
+        self
+    }
+
+    pub fn filter(mut self, filter: ConversationFilter) -> Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn response_url_enabled(mut self, response_url_enabled: bool) -> Self {
+        self.response_url_enabled = response_url_enabled;
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationType {
+    Im,
+    Mpim,
+    Private,
+    Public,
+}
+
+/// Limits which conversations a [`ConversationsSelectElement`] offers.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConversationFilter {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<ConversationType>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub exclude_external_shared_channels: bool,
+}
+
+/// A menu of the workspace's public channels.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "channels_select")]
+pub struct ChannelsSelectElement {
+    pub placeholder: PlainTextObject,
+    pub action_id: String,
+    /// A channel ID.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_channel: Option<String>,
+    /// Includes a `response_url` in the view submission, for posting to the chosen channel.
+    /// Only works in an input block of a modal.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub response_url_enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl ChannelsSelectElement {
+    pub fn new(placeholder: impl Into<String>, action_id: impl Into<String>) -> Self {
+        Self {
+            placeholder: PlainTextObject::new(placeholder),
+            action_id: action_id.into(),
+            initial_channel: None,
+            response_url_enabled: false,
+            confirm: None,
+        }
+    }
+
+    pub fn initial_channel(mut self, initial_channel: impl Into<String>) -> Self {
+        self.initial_channel = Some(initial_channel.into());
+        self
+    }
+
+    pub fn response_url_enabled(mut self, response_url_enabled: bool) -> Self {
+        self.response_url_enabled = response_url_enabled;
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "datepicker")]
+pub struct DatePickerElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainTextObject>,
+    /// `YYYY-MM-DD`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl DatePickerElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_date: None,
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(PlainTextObject::new(placeholder));
+        self
+    }
+
+    pub fn initial_date(mut self, initial_date: impl Into<String>) -> Self {
+        self.initial_date = Some(initial_date.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "timepicker")]
+pub struct TimePickerElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainTextObject>,
+    /// `HH:mm`, 24-hour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_time: Option<String>,
+    /// IANA time zone, e.g. `Asia/Tokyo`. Defaults to the user's own time zone.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+}
+
+impl TimePickerElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_time: None,
+            timezone: None,
+            confirm: None,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(PlainTextObject::new(placeholder));
+        self
+    }
+
+    pub fn initial_time(mut self, initial_time: impl Into<String>) -> Self {
+        self.initial_time = Some(initial_time.into());
+        self
+    }
+
+    pub fn timezone(mut self, timezone: impl Into<String>) -> Self {
+        self.timezone = Some(timezone.into());
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum DispatchActionTrigger {
+    OnEnterPressed,
+    OnCharacterEntered,
+}
+
+/// When an input inside an `input` block with `dispatch_action` sends a `block_actions` payload.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DispatchActionConfig {
+    pub trigger_actions_on: Vec<DispatchActionTrigger>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "plain_text_input")]
+pub struct PlainTextInputElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub multiline: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub focus_on_load: bool,
+}
+
+impl PlainTextInputElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_value: None,
+            multiline: false,
+            min_length: None,
+            max_length: None,
+            dispatch_action_config: None,
+            focus_on_load: false,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(PlainTextObject::new(placeholder));
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    pub fn min_length(mut self, min_length: u32) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    pub fn max_length(mut self, max_length: u32) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn dispatch_action_config(mut self, triggers: Vec<DispatchActionTrigger>) -> Self {
+        self.dispatch_action_config = Some(DispatchActionConfig {
+            trigger_actions_on: triggers,
+        });
+        self
+    }
+
+    /// Focuses this input when the view opens. Only one element per view may set this.
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = focus_on_load;
+        self
+    }
+}
+
+/// A numeric field. Values are strings, as Slack sends them, so decimals keep their precision.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "number_input")]
+pub struct NumberInputElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainTextObject>,
+    /// Required by Slack, so sent even when `false`.
+    pub is_decimal_allowed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub focus_on_load: bool,
+}
+
+impl NumberInputElement {
+    pub fn new(action_id: impl Into<String>, is_decimal_allowed: bool) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            is_decimal_allowed,
+            initial_value: None,
+            min_value: None,
+            max_value: None,
+            dispatch_action_config: None,
+            focus_on_load: false,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(PlainTextObject::new(placeholder));
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn min_value(mut self, min_value: impl Into<String>) -> Self {
+        self.min_value = Some(min_value.into());
+        self
+    }
+
+    pub fn max_value(mut self, max_value: impl Into<String>) -> Self {
+        self.max_value = Some(max_value.into());
+        self
+    }
+
+    pub fn dispatch_action_config(mut self, triggers: Vec<DispatchActionTrigger>) -> Self {
+        self.dispatch_action_config = Some(DispatchActionConfig {
+            trigger_actions_on: triggers,
+        });
+        self
+    }
+
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = focus_on_load;
+        self
+    }
+}
+
+/// A text field that only accepts URLs.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "url_text_input")]
+pub struct UrlInputElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub focus_on_load: bool,
+}
+
+impl UrlInputElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_value: None,
+            dispatch_action_config: None,
+            focus_on_load: false,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(PlainTextObject::new(placeholder));
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn dispatch_action_config(mut self, triggers: Vec<DispatchActionTrigger>) -> Self {
+        self.dispatch_action_config = Some(DispatchActionConfig {
+            trigger_actions_on: triggers,
+        });
+        self
+    }
+
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = focus_on_load;
+        self
+    }
+}
+
+/// A text field that only accepts email addresses.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "email_text_input")]
+pub struct EmailInputElement {
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<PlainTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dispatch_action_config: Option<DispatchActionConfig>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub focus_on_load: bool,
+}
+
+impl EmailInputElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            placeholder: None,
+            initial_value: None,
+            dispatch_action_config: None,
+            focus_on_load: false,
+        }
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(PlainTextObject::new(placeholder));
+        self
+    }
+
+    pub fn initial_value(mut self, initial_value: impl Into<String>) -> Self {
+        self.initial_value = Some(initial_value.into());
+        self
+    }
+
+    pub fn dispatch_action_config(mut self, triggers: Vec<DispatchActionTrigger>) -> Self {
+        self.dispatch_action_config = Some(DispatchActionConfig {
+            trigger_actions_on: triggers,
+        });
+        self
+    }
+
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = focus_on_load;
+        self
+    }
+}
+
+const MAX_FILE_INPUT_FILES: u32 = 10;
+
+/// Lets the user attach files in a modal. Only usable inside an [`InputBlock`].
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "file_input")]
+pub struct FileInputElement {
+    pub action_id: String,
+    /// Accepted file extensions without the dot, e.g. `pdf`. Any type is accepted if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filetypes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_files: Option<u32>,
+}
+
+impl FileInputElement {
+    pub fn new(action_id: impl Into<String>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            filetypes: None,
+            max_files: None,
+        }
+    }
+
+    pub fn filetypes(mut self, filetypes: Vec<String>) -> Self {
+        self.filetypes = Some(filetypes);
+        self
+    }
+
+    /// Fails with [`BlockError::InvalidMaxFiles`] unless `max_files` is between 1 and 10.
+    pub fn max_files(mut self, max_files: u32) -> Result<Self, BlockError> {
+        if !(1..=MAX_FILE_INPUT_FILES).contains(&max_files) {
+            return Err(BlockError::InvalidMaxFiles);
+        }
+        self.max_files = Some(max_files);
+        Ok(self)
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "radio_buttons")]
+pub struct RadioButtonGroupElement {
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<OptionObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub focus_on_load: bool,
+}
+
+impl RadioButtonGroupElement {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            options,
+            initial_option: None,
+            confirm: None,
+            focus_on_load: false,
+        }
+    }
+
+    pub fn initial_option(mut self, initial_option: OptionObject) -> Self {
+        self.initial_option = Some(initial_option);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = focus_on_load;
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "checkboxes")]
+pub struct CheckboxGroupElement {
+    pub action_id: String,
+    pub options: Vec<OptionObject>,
+    /// Must each exactly match one of `options`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_options: Option<Vec<OptionObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<ConfirmDialog>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub focus_on_load: bool,
+}
+
+impl CheckboxGroupElement {
+    pub fn new(action_id: impl Into<String>, options: Vec<OptionObject>) -> Self {
+        Self {
+            action_id: action_id.into(),
+            options,
+            initial_options: None,
+            confirm: None,
+            focus_on_load: false,
+        }
+    }
+
+    pub fn initial_options(mut self, initial_options: Vec<OptionObject>) -> Self {
+        self.initial_options = Some(initial_options);
+        self
+    }
+
+    pub fn confirm(mut self, confirm: ConfirmDialog) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+
+    pub fn focus_on_load(mut self, focus_on_load: bool) -> Self {
+        self.focus_on_load = focus_on_load;
+        self
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename = "section")]
+pub struct SectionBlock {
+    pub text: TextObject,
+    /// Shown as a two-column grid below `text`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<TextObject>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Box<dyn BlockElement>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl SectionBlock {
+    pub fn new(text: impl Into<TextObject>) -> Self {
+        Self {
+            text: text.into(),
+            fields: None,
+            accessory: None,
+            block_id: None,
+        }
+    }
+
+    pub fn fields(mut self, fields: Vec<TextObject>) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    pub fn accessory(mut self, accessory: impl BlockElement + 'static) -> Self {
+        self.accessory = Some(Box::new(accessory));
+        self
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(tag = "type", rename = "divider")]
+pub struct DividerBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl DividerBlock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "image")]
+pub struct ImageBlock {
+    pub image_url: String,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<PlainTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ImageBlock {
+    pub fn new(image_url: impl Into<String>, alt_text: impl Into<String>) -> Self {
+        Self {
+            image_url: image_url.into(),
+            alt_text: alt_text.into(),
+            title: None,
+            block_id: None,
+        }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(PlainTextObject::new(title));
+        self
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+/// Slack rejects context blocks with more elements than this.
+const MAX_CONTEXT_ELEMENTS: usize = 10;
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum ContextElement {
+    Image(ImageElement),
+    Text(MrkdwnObject),
+}
+
+/// A row of small images and text, shown in a muted style. Build one with
+/// [`ContextBlock::builder`] to keep within the element limit.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "context")]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ContextBlock {
+    pub fn builder() -> ContextBlockBuilder {
+        ContextBlockBuilder::default()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ContextBlockBuilder {
+    elements: Vec<ContextElement>,
+    block_id: Option<String>,
+}
+
+impl ContextBlockBuilder {
+    pub fn add_text(self, text: &str) -> Result<Self, BlockError> {
+        self.add(ContextElement::Text(MrkdwnObject::new(text)))
+    }
+
+    pub fn add_image(self, image_url: &str, alt_text: &str) -> Result<Self, BlockError> {
+        self.add(ContextElement::Image(ImageElement::new(
+            image_url, alt_text,
+        )))
+    }
+
+    fn add(mut self, element: ContextElement) -> Result<Self, BlockError> {
+        if self.elements.len() >= MAX_CONTEXT_ELEMENTS {
+            return Err(BlockError::TooManyElements {
+                block: "context block",
+                max: MAX_CONTEXT_ELEMENTS,
+            });
+        }
+        self.elements.push(element);
+        Ok(self)
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn build(self) -> ContextBlock {
+        ContextBlock {
+            elements: self.elements,
+            block_id: self.block_id,
+        }
+    }
+}
+
+/// Slack rejects header text longer than this many characters.
+const MAX_HEADER_TEXT_LEN: usize = 150;
+
+/// Large bold text, used to separate sections of a message or view.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "header")]
+pub struct HeaderBlock {
+    /// Plain text only; header blocks don't render mrkdwn.
+    pub text: PlainTextObject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl HeaderBlock {
+    pub fn new(text: &str) -> Result<Self, BlockError> {
+        if text.chars().count() > MAX_HEADER_TEXT_LEN {
+            return Err(BlockError::TextTooLong {
+                field: "header text",
+                max: MAX_HEADER_TEXT_LEN,
+            });
+        }
+        Ok(Self {
+            text: PlainTextObject::new(text),
+            block_id: None,
+        })
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+/// Formatted text as Slack's own message composer produces it: paragraphs, lists, code blocks
+/// and quotes made of styled spans.
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "rich_text")]
+pub struct RichTextBlock {
+    pub elements: Vec<RichTextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl RichTextBlock {
+    pub fn new(elements: Vec<RichTextElement>) -> Self {
+        Self {
+            elements,
+            block_id: None,
+        }
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum RichTextElement {
+    #[serde(rename = "rich_text_section")]
+    Section { elements: Vec<RichTextSpan> },
+    /// Each item is a section of its own.
+    #[serde(rename = "rich_text_list")]
+    List {
+        style: RichTextListStyle,
+        elements: Vec<RichTextListItem>,
+    },
+    /// A code block.
+    #[serde(rename = "rich_text_preformatted")]
+    Preformatted { elements: Vec<RichTextSpan> },
+    #[serde(rename = "rich_text_quote")]
+    Quote { elements: Vec<RichTextSpan> },
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RichTextListStyle {
+    Bullet,
+    Ordered,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename = "rich_text_section")]
+pub struct RichTextListItem {
+    pub elements: Vec<RichTextSpan>,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichTextSpan {
+    Text {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Channel {
+        channel_id: String,
+    },
+    User {
+        user_id: String,
+    },
+    /// `name` is the emoji name without colons.
+    Emoji {
+        name: String,
+    },
+    /// Shows `url` itself when `text` is `None`.
+    Link {
+        url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+    },
+}
+
+impl RichTextSpan {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            style: None,
+        }
+    }
+
+    pub fn styled(text: impl Into<String>, style: RichTextStyle) -> Self {
+        Self::Text {
+            text: text.into(),
+            style: Some(style),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RichTextStyle {
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub bold: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub italic: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub strike: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub code: bool,
+}
+
+/// Slack rejects actions blocks with more elements than this.
+const MAX_ACTIONS_ELEMENTS: usize = 25;
+
+/// A row of interactive elements. Build one with [`ActionsBlock::builder`] to keep within the
+/// element limit.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename = "actions")]
+pub struct ActionsBlock {
+    pub elements: Vec<Box<dyn ActionElement>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+impl ActionsBlock {
+    pub fn builder() -> ActionsBlockBuilder {
+        ActionsBlockBuilder::default()
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct ActionsBlockBuilder {
+    elements: Vec<Box<dyn ActionElement>>,
+    block_id: Option<String>,
+}
+
+impl ActionsBlockBuilder {
+    pub fn add_element(
+        mut self,
+        element: impl ActionElement + 'static,
+    ) -> Result<Self, BlockError> {
+        if self.elements.len() >= MAX_ACTIONS_ELEMENTS {
+            return Err(BlockError::TooManyElements {
+                block: "actions block",
+                max: MAX_ACTIONS_ELEMENTS,
+            });
+        }
+        self.elements.push(Box::new(element));
+        Ok(self)
+    }
+
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn build(self) -> ActionsBlock {
+        ActionsBlock {
+            elements: self.elements,
+            block_id: self.block_id,
+        }
+    }
+}
+
+/// Slack rejects input block labels and hints longer than this many characters.
+const MAX_INPUT_LABEL_LEN: usize = 2000;
+const MAX_INPUT_HINT_LEN: usize = 2000;
+
+/// A labelled form field in a modal. Build one with [`InputBlock::builder`], which checks the
+/// label and hint lengths.
+#[derive(Serialize, Debug)]
+#[serde(tag = "type", rename = "input")]
+pub struct InputBlock {
+    pub label: PlainTextObject,
+    pub element: Box<dyn InputElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<PlainTextObject>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    /// Sends a `block_actions` payload when the element's value changes, instead of only on submit.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub dispatch_action: bool,
+}
+
+impl InputBlock {
+    pub fn builder(
+        label: impl Into<String>,
+        element: impl InputElement + 'static,
+    ) -> InputBlockBuilder {
+        InputBlockBuilder {
+            label: label.into(),
+            element: Box::new(element),
+            block_id: None,
+            hint: None,
+            optional: false,
+            dispatch_action: false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputBlockBuilder {
+    label: String,
+    element: Box<dyn InputElement>,
+    block_id: Option<String>,
+    hint: Option<String>,
+    optional: bool,
+    dispatch_action: bool,
+}
+
+impl InputBlockBuilder {
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
+        self.block_id = Some(block_id.into());
+        self
+    }
+
+    pub fn hint(mut self, hint: impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    pub fn optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    pub fn dispatch_action(mut self, dispatch_action: bool) -> Self {
+        self.dispatch_action = dispatch_action;
+        self
+    }
+
+    pub fn build(self) -> Result<InputBlock, BlockError> {
+        if self.label.chars().count() > MAX_INPUT_LABEL_LEN {
+            return Err(BlockError::TextTooLong {
+                field: "input label",
+                max: MAX_INPUT_LABEL_LEN,
+            });
+        }
+        if let Some(hint) = &self.hint {
+            if hint.chars().count() > MAX_INPUT_HINT_LEN {
+                return Err(BlockError::TextTooLong {
+                    field: "input hint",
+                    max: MAX_INPUT_HINT_LEN,
+                });
+            }
+        }
+        Ok(InputBlock {
+            label: PlainTextObject::new(self.label),
+            element: self.element,
+            block_id: self.block_id,
+            hint: self.hint.map(PlainTextObject::new),
+            optional: self.optional,
+            dispatch_action: self.dispatch_action,
+        })
+    }
+}
+
+/// Any top-level block. Each variant serializes as its own block object.
+#[derive(Serialize, Debug)]
+#[serde(untagged)]
+pub enum Block {
+    Section(SectionBlock),
+    Image(ImageBlock),
+    Context(ContextBlock),
+    Header(HeaderBlock),
+    RichText(RichTextBlock),
+    Actions(ActionsBlock),
+    Input(InputBlock),
+    Divider(DividerBlock),
+}
+
+impl From<SectionBlock> for Block {
+    fn from(block: SectionBlock) -> Self {
+        Self::Section(block)
+    }
+}
+
+impl From<ImageBlock> for Block {
+    fn from(block: ImageBlock) -> Self {
+        Self::Image(block)
+    }
+}
+
+impl From<ContextBlock> for Block {
+    fn from(block: ContextBlock) -> Self {
+        Self::Context(block)
+    }
+}
+
+impl From<HeaderBlock> for Block {
+    fn from(block: HeaderBlock) -> Self {
+        Self::Header(block)
+    }
+}
+
+impl From<RichTextBlock> for Block {
+    fn from(block: RichTextBlock) -> Self {
+        Self::RichText(block)
+    }
+}
+
+impl From<ActionsBlock> for Block {
+    fn from(block: ActionsBlock) -> Self {
+        Self::Actions(block)
+    }
+}
+
+impl From<InputBlock> for Block {
+    fn from(block: InputBlock) -> Self {
+        Self::Input(block)
+    }
+}
+
+impl From<DividerBlock> for Block {
+    fn from(block: DividerBlock) -> Self {
+        Self::Divider(block)
+    }
+}
+
+// Limits Slack enforces on views, checked by `View::validate`.
+const MAX_VIEW_BLOCKS: usize = 100;
+const MAX_VIEW_TEXT_LEN: usize = 24;
+const MAX_CALLBACK_ID_LEN: usize = 255;
+
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    Modal,
+    Home,
+}
+
+/// A modal or App Home tab, as passed to [`crate::SlackClient::open_view`] and friends.
+#[derive(Serialize, Debug)]
+pub struct View {
+    #[serde(rename = "type")]
+    pub type_: ViewType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<PlainTextObject>,
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<PlainTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<PlainTextObject>,
+    /// Echoed back in `view_submission` payloads to tell views apart.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    /// Echoed back in interaction payloads; up to 3000 characters of the app's own state.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+}
+
+impl View {
+    pub fn modal(title: impl Into<String>) -> Self {
+        Self::new(ViewType::Modal, Some(PlainTextObject::new(title)))
+    }
+
+    pub fn home() -> Self {
+        Self::new(ViewType::Home, None)
+    }
+
+    fn new(type_: ViewType, title: Option<PlainTextObject>) -> Self {
+        Self {
+            type_,
+            title,
+            blocks: Vec::new(),
+            submit: None,
+            close: None,
+            callback_id: None,
+            private_metadata: None,
+        }
+    }
+
+    pub fn block(mut self, block: impl Into<Block>) -> Self {
+        self.blocks.push(block.into());
+        self
+    }
+
+    pub fn submit(mut self, submit: impl Into<String>) -> Self {
+        self.submit = Some(PlainTextObject::new(submit));
+        self
+    }
+
+    pub fn close(mut self, close: impl Into<String>) -> Self {
+        self.close = Some(PlainTextObject::new(close));
+        self
+    }
+
+    pub fn callback_id(mut self, callback_id: impl Into<String>) -> Self {
+        self.callback_id = Some(callback_id.into());
+        self
+    }
+
+    pub fn private_metadata(mut self, private_metadata: impl Into<String>) -> Self {
+        self.private_metadata = Some(private_metadata.into());
+        self
+    }
+
+    /// Checks the limits Slack would otherwise reject the view for with a terse error, and
+    /// returns every violation found.
+    pub fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        if self.blocks.len() > MAX_VIEW_BLOCKS {
+            errors.push(ValidationError::TooManyBlocks {
+                count: self.blocks.len(),
+            });
+        }
+        let mut has_input = false;
+        for (index, block) in self.blocks.iter().enumerate() {
+            if let Block::Input(input) = block {
+                has_input = true;
+                if input.label.text.trim().is_empty() {
+                    errors.push(ValidationError::EmptyInputLabel { index });
+                }
+            }
+        }
+        if self.type_ == ViewType::Modal && has_input && self.submit.is_none() {
+            errors.push(ValidationError::MissingSubmit);
+        }
+        if let Some(callback_id) = &self.callback_id {
+            if callback_id.chars().count() > MAX_CALLBACK_ID_LEN {
+                errors.push(ValidationError::TextTooLong {
+                    field: "callback_id",
+                    max: MAX_CALLBACK_ID_LEN,
+                });
+            }
+        }
+        for (field, text) in [
+            ("title", &self.title),
+            ("submit", &self.submit),
+            ("close", &self.close),
+        ] {
+            if let Some(text) = text {
+                if text.text.chars().count() > MAX_VIEW_TEXT_LEN {
+                    errors.push(ValidationError::TextTooLong {
+                        field,
+                        max: MAX_VIEW_TEXT_LEN,
+                    });
+                }
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// One reason [`View::validate`] rejected a view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    TooManyBlocks {
+        count: usize,
+    },
+    /// `index` is the position of the input block in `blocks`.
+    EmptyInputLabel {
+        index: usize,
+    },
+    /// Modals with input blocks need a submit button.
+    MissingSubmit,
+    TextTooLong {
+        field: &'static str,
+        max: usize,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooManyBlocks { count } => write!(
+                f,
+                "{} blocks exceeds the limit of {}",
+                count, MAX_VIEW_BLOCKS
+            ),
+            Self::EmptyInputLabel { index } => {
+                write!(f, "input block {} has an empty label", index)
+            }
+            Self::MissingSubmit => write!(f, "a modal with input blocks needs a submit button"),
+            Self::TextTooLong { field, max } => {
+                write!(f, "{} is longer than {} characters", field, max)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn to_json(value: impl Serialize) -> serde_json::Value {
+        serde_json::to_value(value).unwrap()
+    }
+
+    #[test]
+    fn rich_text_block_nests_sections_lists_and_spans() {
+        let bold = RichTextStyle {
+            bold: true,
+            ..RichTextStyle::default()
+        };
+        let block = RichTextBlock::new(vec![
+            RichTextElement::Section {
+                elements: vec![
+                    RichTextSpan::text("Hello "),
+                    RichTextSpan::styled("world", bold),
+                    RichTextSpan::Emoji {
+                        name: "wave".to_owned(),
+                    },
+                ],
+            },
+            RichTextElement::List {
+                style: RichTextListStyle::Bullet,
+                elements: vec![RichTextListItem {
+                    elements: vec![RichTextSpan::Link {
+                        url: "https://example.com".to_owned(),
+                        text: None,
+                    }],
+                }],
+            },
+        ])
+        .block_id("rt");
+        assert_eq!(
+            to_json(Block::from(block)),
+            json!({
+                "type": "rich_text",
+                "block_id": "rt",
+                "elements": [
+                    {
+                        "type": "rich_text_section",
+                        "elements": [
+                            { "type": "text", "text": "Hello " },
+                            { "type": "text", "text": "world", "style": { "bold": true } },
+                            { "type": "emoji", "name": "wave" },
+                        ],
+                    },
+                    {
+                        "type": "rich_text_list",
+                        "style": "bullet",
+                        "elements": [
+                            {
+                                "type": "rich_text_section",
+                                "elements": [{ "type": "link", "url": "https://example.com" }],
+                            },
+                        ],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn rich_text_style_skips_false_flags() {
+        assert_eq!(to_json(RichTextStyle::default()), json!({}));
+        let style = RichTextStyle {
+            italic: true,
+            code: true,
+            ..RichTextStyle::default()
+        };
+        assert_eq!(to_json(style), json!({ "italic": true, "code": true }));
+    }
+
+    #[test]
+    fn section_block_serializes_accessory() {
+        let block = SectionBlock::new(TextObject::mrkdwn("*Deploy?*"))
+            .accessory(ButtonElement::new("Go", "deploy").style(ButtonStyle::Primary));
+        assert_eq!(
+            to_json(Block::from(block)),
+            json!({
+                "type": "section",
+                "text": { "type": "mrkdwn", "text": "*Deploy?*" },
+                "accessory": {
+                    "type": "button",
+                    "text": { "type": "plain_text", "text": "Go" },
+                    "action_id": "deploy",
+                    "style": "primary",
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn context_block_elements_keep_their_own_type() {
+        let block = ContextBlock::builder()
+            .add_image("https://example.com/a.png", "avatar")
+            .unwrap()
+            .add_text("by <@U123>")
+            .unwrap()
+            .build();
+        assert_eq!(
+            to_json(Block::from(block)),
+            json!({
+                "type": "context",
+                "elements": [
+                    {
+                        "type": "image",
+                        "image_url": "https://example.com/a.png",
+                        "alt_text": "avatar",
+                    },
+                    { "type": "mrkdwn", "text": "by <@U123>" },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn input_block_skips_unset_flags() {
+        let block = InputBlock::builder("Name", PlainTextInputElement::new("name"))
+            .build()
+            .unwrap();
+        assert_eq!(
+            to_json(Block::from(block)),
+            json!({
+                "type": "input",
+                "label": { "type": "plain_text", "text": "Name" },
+                "element": { "type": "plain_text_input", "action_id": "name" },
+            })
+        );
+
+        let block = InputBlock::builder("Name", PlainTextInputElement::new("name"))
+            .optional(true)
+            .dispatch_action(true)
+            .build()
+            .unwrap();
+        let value = to_json(block);
+        assert_eq!(value["optional"], json!(true));
+        assert_eq!(value["dispatch_action"], json!(true));
+    }
+
+    #[test]
+    fn view_serializes_type_and_skips_unset_fields() {
+        let view = View::modal("Feedback")
+            .submit("Send")
+            .callback_id("feedback")
+            .block(DividerBlock::new());
+        assert_eq!(
+            to_json(view),
+            json!({
+                "type": "modal",
+                "title": { "type": "plain_text", "text": "Feedback" },
+                "blocks": [{ "type": "divider" }],
+                "submit": { "type": "plain_text", "text": "Send" },
+                "callback_id": "feedback",
+            })
+        );
+        assert_eq!(
+            to_json(View::home()),
+            json!({ "type": "home", "blocks": [] })
+        );
+    }
+
+    fn options(count: usize) -> Vec<OptionObject> {
+        (0..count)
+            .map(|i| OptionObject::new(format!("Option {}", i), i.to_string()))
+            .collect()
+    }
+
+    fn overflow_with(count: usize) -> Result<OverflowMenuBuilder, BlockError> {
+        options(count)
+            .into_iter()
+            .try_fold(OverflowMenuElement::builder("more"), |builder, option| {
+                builder.add_option(option)
+            })
+    }
+
+    #[test]
+    fn overflow_menu_takes_two_to_five_options() {
+        assert_eq!(
+            overflow_with(1).unwrap().build().unwrap_err(),
+            BlockError::TooFewElements {
+                block: "overflow menu",
+                min: MIN_OVERFLOW_OPTIONS,
+            }
+        );
+        assert_eq!(overflow_with(2).unwrap().build().unwrap().options.len(), 2);
+        assert_eq!(overflow_with(5).unwrap().build().unwrap().options.len(), 5);
+        assert_eq!(
+            overflow_with(6).unwrap_err(),
+            BlockError::TooManyElements {
+                block: "overflow menu",
+                max: MAX_OVERFLOW_OPTIONS,
+            }
+        );
+    }
+
+    #[test]
+    fn context_block_takes_at_most_ten_elements() {
+        let full = (0..MAX_CONTEXT_ELEMENTS)
+            .try_fold(ContextBlock::builder(), |builder, i| {
+                builder.add_text(&i.to_string())
+            })
+            .unwrap();
+        assert_eq!(
+            full.clone()
+                .add_image("https://example.com/a.png", "a")
+                .unwrap_err(),
+            BlockError::TooManyElements {
+                block: "context block",
+                max: MAX_CONTEXT_ELEMENTS,
+            }
+        );
+        assert_eq!(full.build().elements.len(), MAX_CONTEXT_ELEMENTS);
+    }
+
+    #[test]
+    fn actions_block_takes_at_most_twenty_five_elements() {
+        let add = |builder: ActionsBlockBuilder, i: usize| {
+            builder.add_element(ButtonElement::new("Go", format!("go_{}", i)))
+        };
+        let full = (0..MAX_ACTIONS_ELEMENTS)
+            .try_fold(ActionsBlock::builder(), add)
+            .unwrap();
+        assert_eq!(
+            (0..=MAX_ACTIONS_ELEMENTS)
+                .try_fold(ActionsBlock::builder(), add)
+                .unwrap_err(),
+            BlockError::TooManyElements {
+                block: "actions block",
+                max: MAX_ACTIONS_ELEMENTS,
+            }
+        );
+        assert_eq!(full.build().elements.len(), MAX_ACTIONS_ELEMENTS);
+    }
+
+    #[test]
+    fn header_text_is_limited_to_150_characters() {
+        // Multi-byte characters check the limit counts characters, not bytes.
+        assert!(HeaderBlock::new(&"é".repeat(MAX_HEADER_TEXT_LEN)).is_ok());
+        assert_eq!(
+            HeaderBlock::new(&"é".repeat(MAX_HEADER_TEXT_LEN + 1)).unwrap_err(),
+            BlockError::TextTooLong {
+                field: "header text",
+                max: MAX_HEADER_TEXT_LEN,
+            }
+        );
+    }
+
+    #[test]
+    fn input_label_and_hint_are_limited_to_2000_characters() {
+        let input = |label: usize, hint: usize| {
+            InputBlock::builder("a".repeat(label), PlainTextInputElement::new("field"))
+                .hint("a".repeat(hint))
+                .build()
+        };
+        assert!(input(MAX_INPUT_LABEL_LEN, MAX_INPUT_HINT_LEN).is_ok());
+        assert_eq!(
+            input(MAX_INPUT_LABEL_LEN + 1, 1).unwrap_err(),
+            BlockError::TextTooLong {
+                field: "input label",
+                max: MAX_INPUT_LABEL_LEN,
+            }
+        );
+        assert_eq!(
+            input(1, MAX_INPUT_HINT_LEN + 1).unwrap_err(),
+            BlockError::TextTooLong {
+                field: "input hint",
+                max: MAX_INPUT_HINT_LEN,
+            }
+        );
+    }
+
+    #[test]
+    fn multi_selects_need_max_selected_items_of_at_least_one() {
+        let static_select = MultiStaticSelectElement::new("Pick", "pick", options(2));
+        assert_eq!(
+            static_select.clone().max_selected_items(0).unwrap_err(),
+            BlockError::InvalidMaxSelectedItems
+        );
+        assert_eq!(
+            static_select
+                .max_selected_items(1)
+                .unwrap()
+                .max_selected_items,
+            Some(1)
+        );
+
+        let external = MultiExternalSelectElement::new("Pick", "pick");
+        assert_eq!(
+            external.clone().max_selected_items(0).unwrap_err(),
+            BlockError::InvalidMaxSelectedItems
+        );
+        assert!(external.max_selected_items(1).is_ok());
+
+        let users = MultiUsersSelectElement::new("Pick", "pick");
+        assert_eq!(
+            users.clone().max_selected_items(0).unwrap_err(),
+            BlockError::InvalidMaxSelectedItems
+        );
+        assert!(users.max_selected_items(1).is_ok());
+    }
+
+    #[test]
+    fn file_input_takes_one_to_ten_files() {
+        let input = FileInputElement::new("files");
+        assert_eq!(
+            input.clone().max_files(0).unwrap_err(),
+            BlockError::InvalidMaxFiles
+        );
+        assert!(input.clone().max_files(1).is_ok());
+        assert_eq!(
+            input
+                .clone()
+                .max_files(MAX_FILE_INPUT_FILES)
+                .unwrap()
+                .max_files,
+            Some(MAX_FILE_INPUT_FILES)
+        );
+        assert_eq!(
+            input.max_files(MAX_FILE_INPUT_FILES + 1).unwrap_err(),
+            BlockError::InvalidMaxFiles
+        );
+    }
+
+    fn input(label: &str) -> InputBlock {
+        InputBlock::builder(label, PlainTextInputElement::new("field"))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn valid_modal_and_home_pass_validation() {
+        let modal = View::modal("a".repeat(MAX_VIEW_TEXT_LEN))
+            .submit("Send")
+            .close("Cancel")
+            .callback_id("a".repeat(MAX_CALLBACK_ID_LEN))
+            .block(input("Name"));
+        assert_eq!(modal.validate(), Ok(()));
+        assert_eq!(View::home().validate(), Ok(()));
+    }
+
+    #[test]
+    fn view_with_too_many_blocks_is_rejected() {
+        let full =
+            (0..MAX_VIEW_BLOCKS).fold(View::home(), |view, _| view.block(DividerBlock::new()));
+        assert_eq!(full.validate(), Ok(()));
+        assert_eq!(
+            full.block(DividerBlock::new()).validate(),
+            Err(vec![ValidationError::TooManyBlocks {
+                count: MAX_VIEW_BLOCKS + 1
+            }])
+        );
+    }
+
+    #[test]
+    fn input_block_with_blank_label_is_rejected() {
+        let view = View::modal("Form")
+            .submit("Send")
+            .block(DividerBlock::new())
+            .block(input(" "));
+        assert_eq!(
+            view.validate(),
+            Err(vec![ValidationError::EmptyInputLabel { index: 1 }])
+        );
+    }
+
+    #[test]
+    fn modal_with_input_needs_submit() {
+        assert_eq!(
+            View::modal("Form").block(input("Name")).validate(),
+            Err(vec![ValidationError::MissingSubmit])
+        );
+        // Home tabs have no submit button to require.
+        assert_eq!(View::home().block(input("Name")).validate(), Ok(()));
+    }
+
+    #[test]
+    fn view_texts_and_callback_id_are_length_limited() {
+        let too_long = "a".repeat(MAX_VIEW_TEXT_LEN + 1);
+        for (field, view) in [
+            ("title", View::modal(too_long.as_str())),
+            ("submit", View::modal("Form").submit(too_long.as_str())),
+            ("close", View::modal("Form").close(too_long.as_str())),
+        ] {
+            assert_eq!(
+                view.validate(),
+                Err(vec![ValidationError::TextTooLong {
+                    field,
+                    max: MAX_VIEW_TEXT_LEN
+                }])
+            );
+        }
+        assert_eq!(
+            View::home()
+                .callback_id("a".repeat(MAX_CALLBACK_ID_LEN + 1))
+                .validate(),
+            Err(vec![ValidationError::TextTooLong {
+                field: "callback_id",
+                max: MAX_CALLBACK_ID_LEN
+            }])
+        );
+    }
+
+    #[test]
+    fn validate_reports_every_violation() {
+        let view = View::modal("a".repeat(MAX_VIEW_TEXT_LEN + 1))
+            .callback_id("a".repeat(MAX_CALLBACK_ID_LEN + 1))
+            .block(input(""));
+        assert_eq!(
+            view.validate(),
+            Err(vec![
+                ValidationError::EmptyInputLabel { index: 0 },
+                ValidationError::MissingSubmit,
+                ValidationError::TextTooLong {
+                    field: "callback_id",
+                    max: MAX_CALLBACK_ID_LEN
+                },
+                ValidationError::TextTooLong {
+                    field: "title",
+                    max: MAX_VIEW_TEXT_LEN
+                },
+            ])
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::SlackClient;
+use crate::error::SlackApiError;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BookmarkType {
+    Link,
+    File,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Bookmark {
+    pub id: String,
+    pub channel_id: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub type_: BookmarkType,
+    pub link: Option<String>,
+    pub emoji: Option<String>,
+    pub date_created: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BookmarksListResponse {
+    pub bookmarks: Vec<Bookmark>,
+}
+
+#[derive(Deserialize)]
+struct BookmarkResponse {
+    bookmark: Bookmark,
+}
+
+impl SlackClient {
+    /// Adds a bookmark to the channel header. `link` is required for [`BookmarkType::Link`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn add_bookmark(
+        &self,
+        channel_id: &str,
+        title: &str,
+        type_: BookmarkType,
+        link: Option<&str>,
+        emoji: Option<&str>,
+    ) -> Result<Bookmark, SlackApiError> {
+        let res: BookmarkResponse = self
+            .api_post(
+                "bookmarks.add",
+                &serde_json::json!({
+                    "channel_id": channel_id,
+                    "title": title,
+                    "type": type_,
+                    "link": link,
+                    "emoji": emoji,
+                }),
+            )
+            .await?;
+        Ok(res.bookmark)
+    }
+
+    /// Changes a bookmark. Fields left as `None` are kept.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn edit_bookmark(
+        &self,
+        channel_id: &str,
+        bookmark_id: &str,
+        title: Option<&str>,
+        link: Option<&str>,
+        emoji: Option<&str>,
+    ) -> Result<Bookmark, SlackApiError> {
+        let mut body = serde_json::json!({
+            "channel_id": channel_id,
+            "bookmark_id": bookmark_id,
+        });
+        for (key, value) in [("title", title), ("link", link), ("emoji", emoji)] {
+            if let Some(value) = value {
+                body[key] = value.into();
+            }
+        }
+        let res: BookmarkResponse = self.api_post("bookmarks.edit", &body).await?;
+        Ok(res.bookmark)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn remove_bookmark(
+        &self,
+        channel_id: &str,
+        bookmark_id: &str,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "bookmarks.remove",
+            &serde_json::json!({
+                "channel_id": channel_id,
+                "bookmark_id": bookmark_id,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_bookmarks(
+        &self,
+        channel_id: &str,
+    ) -> Result<BookmarksListResponse, SlackApiError> {
+        self.api_get(
+            "bookmarks.list",
+            &serde_json::json!({ "channel_id": channel_id }),
+        )
+        .await
+    }
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::SlackClient;
+use crate::error::SlackApiError;
+
+/// Someone in a call: either a Slack user (`slack_id`) or an external one (`external_id`, usually
+/// with a `display_name` and `avatar_url`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CallParticipant {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slack_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Call {
+    pub id: String,
+    pub join_url: String,
+    pub desktop_app_join_url: Option<String>,
+    pub date_start: i64,
+    #[serde(default)]
+    pub active_participants: Vec<CallParticipant>,
+    pub title: Option<String>,
+}
+
+/// Parameters of `calls.add`. `external_unique_id` is the calling app's own ID for the call.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct AddCallRequest {
+    pub external_unique_id: String,
+    pub join_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_start: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub desktop_app_join_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CallResponse {
+    call: Call,
+}
+
+impl SlackClient {
+    /// Registers a call so it can be shared in a channel with a `call` block.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn add_call(&self, request: &AddCallRequest) -> Result<Call, SlackApiError> {
+        let res: CallResponse = self.api_post("calls.add", request).await?;
+        Ok(res.call)
+    }
+
+    /// Changes a call's title or join URLs. Fields left as `None` are kept.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_call(
+        &self,
+        id: &str,
+        title: Option<&str>,
+        join_url: Option<&str>,
+        desktop_app_join_url: Option<&str>,
+    ) -> Result<Call, SlackApiError> {
+        let mut body = serde_json::json!({ "id": id });
+        for (key, value) in [
+            ("title", title),
+            ("join_url", join_url),
+            ("desktop_app_join_url", desktop_app_join_url),
+        ] {
+            if let Some(value) = value {
+                body[key] = value.into();
+            }
+        }
+        let res: CallResponse = self.api_post("calls.update", &body).await?;
+        Ok(res.call)
+    }
+
+    /// Ends a call. Without `duration`, Slack works it out from the start date.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn end_call(
+        &self,
+        id: &str,
+        duration: Option<Duration>,
+    ) -> Result<(), SlackApiError> {
+        let mut body = serde_json::json!({ "id": id });
+        if let Some(duration) = duration {
+            body["duration"] = duration.as_secs().into();
+        }
+        self.api_post::<serde_json::Value>("calls.end", &body)
+            .await?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn add_call_participants(
+        &self,
+        id: &str,
+        users: &[CallParticipant],
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "calls.participants.add",
+            &serde_json::json!({ "id": id, "users": users }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn remove_call_participants(
+        &self,
+        id: &str,
+        users: &[CallParticipant],
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "calls.participants.remove",
+            &serde_json::json!({ "id": id, "users": users }),
+        )
+        .await?;
+        Ok(())
+    }
+}
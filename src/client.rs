@@ -0,0 +1,1752 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use isahc::config::Configurable;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::blocks::{Block, View};
+use crate::error::SlackApiError;
+use crate::metrics::{Metrics, NoopMetrics};
+use crate::pagination::{Page, Paginator};
+use crate::response_url::{ResponseUrlClient, ResponseUrlMessage};
+
+/// Sent as the `User-Agent` of every request unless a client builder overrides it.
+pub const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Whether `user_agent` can be sent as a header both by surf and in the WebSocket handshake,
+/// which means visible ASCII and spaces only.
+pub(crate) fn is_valid_user_agent(user_agent: &str) -> bool {
+    user_agent.is_ascii() && tungstenite::http::HeaderValue::from_str(user_agent).is_ok()
+}
+
+pub(crate) fn http_client(proxy: Option<&url::Url>, user_agent: &str) -> surf::Client {
+    let config = surf::Config::new()
+        .add_header("User-Agent", user_agent)
+        .expect("User-Agent is checked by is_valid_user_agent");
+    let config = match proxy {
+        None => config,
+        Some(proxy) => {
+            let client = isahc::HttpClient::builder()
+                .proxy(
+                    proxy
+                        .as_str()
+                        .parse::<isahc::http::Uri>()
+                        .expect("url::Url is always a valid URI"),
+                )
+                .build()
+                .expect("Failed to build proxied HTTP client");
+            config.set_http_client(http_client::isahc::IsahcClient::from_client(client))
+        }
+    };
+    config.try_into().expect("Failed to build HTTP client")
+}
+
+async fn receive_api_response<T: DeserializeOwned>(
+    method: &'static str,
+    res: surf::Response,
+) -> Result<T, SlackApiError> {
+    parse_api_response(method, receive_api_json(method, res).await?)
+}
+
+/// Reads the JSON body of a response, turning a 429 into [`SlackApiError::RateLimited`].
+/// For APIs whose successful responses carry no `ok` field; others go through
+/// [`receive_api_response`].
+pub(crate) async fn receive_api_json(
+    method: &'static str,
+    mut res: surf::Response,
+) -> Result<serde_json::Value, SlackApiError> {
+    debug!("{} responded with {}", method, res.status());
+    if res.status() == surf::StatusCode::TooManyRequests {
+        let retry_after = res
+            .header("Retry-After")
+            .and_then(|v| v.last().as_str().parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(1));
+        warn!("{} was rate limited, retry after {:?}", method, retry_after);
+        return Err(SlackApiError::RateLimited {
+            method,
+            retry_after,
+        });
+    }
+    Ok(res.body_json().await?)
+}
+
+/// The `ts` every call made by a [`SlackClient::dry_run`] client reports.
+pub const DRY_RUN_TS: &str = "0000000000.000000";
+
+fn dry_run_response<T: DeserializeOwned>(
+    method: &'static str,
+    body: &impl Serialize,
+) -> Result<T, SlackApiError> {
+    info!(
+        "[dry run] {} {}",
+        method,
+        serde_json::to_string(body).unwrap_or_default()
+    );
+    parse_api_response(
+        method,
+        serde_json::json!({
+            "ok": true,
+            "ts": DRY_RUN_TS,
+            "message_ts": DRY_RUN_TS,
+        }),
+    )
+}
+
+pub(crate) fn parse_api_response<T: DeserializeOwned>(
+    method: &'static str,
+    value: serde_json::Value,
+) -> Result<T, SlackApiError> {
+    if value.get("ok").and_then(|v| v.as_bool()) != Some(true) {
+        let code = value
+            .get("error")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown_error");
+        error!("{} failed: {}", method, code);
+        return Err(SlackApiError::from_code(method, code));
+    }
+    // Successful calls can still flag problems, e.g. `missing_charset` or `superfluous_charset`.
+    if let Some(warning) = value.get("warning").and_then(|v| v.as_str()) {
+        warn!("{} succeeded with warning: {}", method, warning);
+    }
+    for warning in value["response_metadata"]["warnings"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str())
+    {
+        warn!("{} succeeded with warning: {}", method, warning);
+    }
+    serde_json::from_value(value).map_err(|source| SlackApiError::Decode { method, source })
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ChannelTopic {
+    pub value: String,
+    pub creator: String,
+    pub last_set: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Channel {
+    pub id: String,
+    pub name: Option<String>,
+    pub topic: Option<ChannelTopic>,
+    pub purpose: Option<ChannelTopic>,
+    #[serde(default)]
+    pub is_channel: bool,
+    #[serde(default)]
+    pub is_group: bool,
+    #[serde(default)]
+    pub is_im: bool,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    #[serde(default)]
+    pub is_member: bool,
+}
+
+pub type UserId = String;
+
+const PAGE_SIZE: u32 = 200;
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ResponseMetadata {
+    #[serde(default)]
+    pub next_cursor: String,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ChannelMembersResponse {
+    members: Vec<UserId>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Message {
+    pub ts: String,
+    #[serde(default)]
+    pub text: String,
+    pub user: Option<UserId>,
+    pub bot_id: Option<String>,
+    pub subtype: Option<String>,
+    pub thread_ts: Option<String>,
+    pub reply_count: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Reaction {
+    pub name: String,
+    pub count: u32,
+    pub users: Vec<UserId>,
+}
+
+#[derive(Deserialize)]
+struct ReactionsGetResponse {
+    message: ReactedMessage,
+}
+
+#[derive(Deserialize)]
+struct ReactedMessage {
+    #[serde(default)]
+    reactions: Vec<Reaction>,
+}
+
+#[derive(Deserialize)]
+struct ThreadRepliesResponse {
+    messages: Vec<Message>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Deserialize)]
+struct ChannelInfoResponse {
+    channel: Channel,
+}
+
+#[derive(Deserialize)]
+struct ConversationsListResponse {
+    channels: Vec<Channel>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Deserialize)]
+struct ConversationsHistoryResponse {
+    messages: Vec<Message>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Team {
+    pub id: String,
+    pub name: String,
+    pub domain: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BotProfile {
+    pub id: String,
+    pub name: String,
+    /// Missing for legacy bot users that don't belong to an app.
+    pub app_id: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct BotInfoResponse {
+    bot: BotProfile,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdminUserInfo {
+    pub id: UserId,
+    pub email: Option<String>,
+    #[serde(default)]
+    pub is_admin: bool,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub is_primary_owner: bool,
+    /// IDs of the org's workspaces the user belongs to.
+    #[serde(default)]
+    pub teams: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct AdminUsersListResponse {
+    users: Vec<AdminUserInfo>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Serialize, Debug, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ConvSort {
+    #[default]
+    Relevant,
+    Name,
+    MemberCount,
+    Created,
+}
+
+/// A channel as `admin.conversations.search` reports it. Unlike [`Channel`], the purpose is a
+/// plain string here.
+#[derive(Deserialize, Debug, Clone)]
+pub struct AdminConversation {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub purpose: String,
+    #[serde(default)]
+    pub member_count: u32,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_archived: bool,
+    pub created: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct AdminConvSearchResponse {
+    conversations: Vec<AdminConversation>,
+    /// Unlike most methods, `admin.conversations.search` returns its cursor at the top level.
+    #[serde(default)]
+    next_cursor: String,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct EventAuthorization {
+    pub enterprise_id: Option<String>,
+    pub team_id: Option<String>,
+    pub user_id: UserId,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+#[derive(Deserialize)]
+struct EventAuthorizationsResponse {
+    authorizations: Vec<EventAuthorization>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct WorkflowStepError {
+    /// Shown to the workflow's owner in Workflow Builder's activity log.
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+struct EmojiListResponse {
+    emoji: HashMap<String, String>,
+}
+
+/// Custom emoji rarely change, so [`SlackClient::list_emoji`] only refetches them this often.
+const EMOJI_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// A user group, mentionable as `@handle`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Usergroup {
+    pub id: String,
+    pub handle: String,
+    pub name: String,
+    #[serde(default)]
+    pub users: Vec<UserId>,
+}
+
+#[derive(Deserialize)]
+struct UsergroupsListResponse {
+    usergroups: Vec<Usergroup>,
+}
+
+#[derive(Deserialize)]
+struct UsergroupUsersResponse {
+    users: Vec<UserId>,
+}
+
+#[derive(Deserialize)]
+struct PermalinkResponse {
+    permalink: String,
+}
+
+#[derive(Deserialize)]
+struct IdOnly {
+    id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct User {
+    pub id: UserId,
+    pub team_id: String,
+    pub name: String,
+    pub real_name: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub is_bot: bool,
+    /// IANA time zone name, e.g. `America/Los_Angeles`.
+    pub tz: Option<String>,
+    pub profile: UserProfile,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct UserProfile {
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub real_name: String,
+    /// Only returned to tokens with the `users:read.email` scope.
+    pub email: Option<String>,
+    pub image_72: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LookupByEmailResponse {
+    user: User,
+}
+
+#[derive(Deserialize)]
+struct UsersListResponse {
+    members: Vec<User>,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Deserialize)]
+struct ConversationsOpenResponse {
+    channel: IdOnly,
+}
+
+#[derive(Deserialize)]
+struct InviteResponse {
+    #[serde(default)]
+    errors: Vec<InviteFailure>,
+}
+
+#[derive(Deserialize)]
+struct InviteFailure {
+    user: UserId,
+    error: String,
+}
+
+#[derive(Deserialize)]
+struct TeamInfoResponse {
+    team: Team,
+}
+
+/// Optional `chat.postMessage` settings. Unset fields are left out of the request,
+/// so Slack's defaults apply.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct PostOptions {
+    /// Posts as a reply in this thread.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<String>,
+    /// Also shows a threaded reply in the channel. Only valid together with `thread_ts`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_broadcast: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_names: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn: Option<bool>,
+    /// Overrides the displayed name. Like `icon_emoji` and `icon_url`, this only works with bot
+    /// tokens that have `chat:write.customize`; other tokens get
+    /// [`SlackApiError::NotAllowedTokenType`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_emoji: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+/// Escapes `&`, `<` and `>` as Slack requires for message text, which also defuses
+/// `<!here>`-style special mentions. With `neutralize_broadcasts`, plain `@here`, `@channel`
+/// and `@everyone` get a zero-width space after the `@` so `link_names` can't turn them into pings.
+pub fn sanitize_for_mrkdwn(text: &str, neutralize_broadcasts: bool) -> String {
+    let escaped = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    if !neutralize_broadcasts {
+        return escaped;
+    }
+    ["here", "channel", "everyone"]
+        .iter()
+        .fold(escaped, |text, mention| {
+            text.replace(&format!("@{}", mention), &format!("@\u{200B}{}", mention))
+        })
+}
+
+/// A link preview for `chat.unfurl`: an attachment object, or `{ "blocks": [...] }`.
+pub type Unfurl = serde_json::Value;
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct PostMessageResponse {
+    #[serde(default)]
+    pub channel: String,
+    /// Identifies the posted message, e.g. as the `thread_ts` for replies to it.
+    pub ts: String,
+    pub warning: Option<String>,
+    #[serde(default)]
+    pub response_metadata: ResponseMetadata,
+}
+
+#[derive(Serialize)]
+struct PostMessageRequest<'a> {
+    channel: &'a str,
+    #[serde(flatten)]
+    body: &'a MessageBody,
+    #[serde(flatten)]
+    options: &'a PostOptions,
+}
+
+/// The content of a message. With `blocks` or `attachments`, `text` becomes the fallback shown
+/// in notifications.
+#[derive(Serialize, Debug, Default)]
+pub struct MessageBody {
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<serde_json::Value>,
+}
+
+impl MessageBody {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn blocks(mut self, blocks: Vec<Block>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    pub fn attachments(mut self, attachments: Vec<serde_json::Value>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+}
+
+impl From<&str> for MessageBody {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl From<String> for MessageBody {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+#[derive(Serialize)]
+struct PostEphemeralRequest<'a> {
+    channel: &'a str,
+    user: &'a str,
+    #[serde(flatten)]
+    body: &'a MessageBody,
+}
+
+#[derive(Deserialize)]
+struct PostEphemeralResponse {
+    message_ts: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RevokeResponse {
+    pub ok: bool,
+    pub revoked: bool,
+}
+
+#[derive(Deserialize)]
+struct ViewId {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct ViewResponse {
+    view: ViewId,
+}
+
+#[derive(Deserialize)]
+struct ScheduleMessageResponse {
+    scheduled_message_id: String,
+}
+
+const MAX_CHANNEL_NAME_LEN: usize = 80;
+
+/// Slack refuses to schedule messages further ahead than this.
+const MAX_SCHEDULE_AHEAD: Duration = Duration::from_secs(120 * 24 * 60 * 60);
+
+pub struct SlackClient {
+    token: RwLock<String>,
+    http: surf::Client,
+    metrics: Arc<dyn Metrics>,
+    request_permits: Option<async_lock::Semaphore>,
+    channel_names: Mutex<HashMap<String, String>>,
+    emoji: Mutex<Option<(Instant, HashMap<String, String>)>>,
+    bots: Mutex<HashMap<String, BotProfile>>,
+    dry_run: bool,
+}
+
+pub struct SlackClientBuilder {
+    token: String,
+    proxy: Option<url::Url>,
+    metrics: Option<Arc<dyn Metrics>>,
+    max_concurrent_requests: Option<usize>,
+    dry_run: bool,
+    user_agent: String,
+}
+
+impl SlackClientBuilder {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            token: token.into(),
+            proxy: None,
+            metrics: None,
+            max_concurrent_requests: None,
+            dry_run: false,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+        }
+    }
+
+    /// Routes all Web API calls through an HTTP(S) proxy.
+    pub fn proxy(mut self, proxy: url::Url) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Caps the number of Web API calls in flight; further calls wait for a slot.
+    /// Unbounded by default.
+    pub fn max_concurrent_requests(mut self, limit: usize) -> Self {
+        self.max_concurrent_requests = Some(limit);
+        self
+    }
+
+    /// Replaces [`DEFAULT_USER_AGENT`], e.g. with the bot's own name and a contact URL.
+    /// Fails with [`SlackApiError::InvalidUserAgent`] unless it is printable ASCII.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Result<Self, SlackApiError> {
+        let user_agent = user_agent.into();
+        if !is_valid_user_agent(&user_agent) {
+            return Err(SlackApiError::InvalidUserAgent(user_agent));
+        }
+        self.user_agent = user_agent;
+        Ok(self)
+    }
+
+    /// See [`SlackClient::dry_run`].
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    pub fn build(self) -> SlackClient {
+        SlackClient {
+            token: RwLock::new(self.token),
+            http: http_client(self.proxy.as_ref(), &self.user_agent),
+            metrics: self.metrics.unwrap_or_else(|| Arc::new(NoopMetrics)),
+            request_permits: self.max_concurrent_requests.map(async_lock::Semaphore::new),
+            channel_names: Mutex::new(HashMap::new()),
+            emoji: Mutex::new(None),
+            bots: Mutex::new(HashMap::new()),
+            dry_run: self.dry_run,
+        }
+    }
+}
+
+impl SlackClient {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self::builder(token).build()
+    }
+
+    /// A client that logs each Web API call instead of sending it and answers with a canned
+    /// success response whose `ts` is always [`DRY_RUN_TS`]. Calls that expect more than that
+    /// in the response, such as [`Self::channel_info`], fail with [`SlackApiError::Decode`].
+    pub fn dry_run(token: impl Into<String>) -> Self {
+        Self::builder(token).dry_run(true).build()
+    }
+
+    pub fn builder(token: impl Into<String>) -> SlackClientBuilder {
+        SlackClientBuilder::new(token)
+    }
+
+    /// Swaps the token used for subsequent requests, e.g. after a rotation, without rebuilding the client.
+    pub fn set_token(&self, token: impl Into<String>) {
+        *self.token.write().expect("token lock poisoned") = token.into();
+    }
+
+    fn bearer(&self) -> String {
+        format!("Bearer {}", self.token.read().expect("token lock poisoned"))
+    }
+
+    pub(crate) async fn api_get<T: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        params: &impl Serialize,
+    ) -> Result<T, SlackApiError> {
+        if self.dry_run {
+            return dry_run_response(method, params);
+        }
+        debug!("GET {}", method);
+        let request = self
+            .http
+            .get(format!("https://slack.com/api/{}", method))
+            .header(surf::http::headers::AUTHORIZATION, self.bearer())
+            .query(params)?;
+        self.send_request(method, request).await
+    }
+
+    pub(crate) async fn api_post<T: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        body: &impl Serialize,
+    ) -> Result<T, SlackApiError> {
+        if self.dry_run {
+            return dry_run_response(method, body);
+        }
+        debug!("POST {}", method);
+        let request = self
+            .http
+            .post(format!("https://slack.com/api/{}", method))
+            .header(surf::http::headers::AUTHORIZATION, self.bearer())
+            .header(
+                surf::http::headers::CONTENT_TYPE,
+                "application/json; charset=utf-8",
+            )
+            .body_json(body)?;
+        self.send_request(method, request).await
+    }
+
+    async fn send_request<T: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        request: surf::RequestBuilder,
+    ) -> Result<T, SlackApiError> {
+        let _permit = match &self.request_permits {
+            Some(permits) => Some(permits.acquire().await),
+            None => None,
+        };
+        let started = Instant::now();
+        let res = request.send().await?;
+        self.metrics
+            .record_request(method, res.status().into(), started.elapsed());
+        receive_api_response(method, res).await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn channel_info(&self, channel: &str) -> Result<Channel, SlackApiError> {
+        let res: ChannelInfoResponse = self
+            .api_get(
+                "conversations.info",
+                &serde_json::json!({ "channel": channel }),
+            )
+            .await?;
+        if let Some(name) = &res.channel.name {
+            self.channel_names
+                .lock()
+                .expect("channel name cache poisoned")
+                .insert(res.channel.id.clone(), name.clone());
+        }
+        Ok(res.channel)
+    }
+
+    /// Resolves a channel ID to its name, hitting `conversations.info` only on a cache miss.
+    /// DMs have no name, in which case the ID itself is returned.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn channel_name(&self, channel: &str) -> Result<String, SlackApiError> {
+        if let Some(name) = self
+            .channel_names
+            .lock()
+            .expect("channel name cache poisoned")
+            .get(channel)
+        {
+            return Ok(name.clone());
+        }
+        Ok(self
+            .channel_info(channel)
+            .await?
+            .name
+            .unwrap_or_else(|| channel.to_owned()))
+    }
+
+    /// Looks up the bot behind a message's `bot_id`, hitting `bots.info` only on a cache miss.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn bot_info(&self, bot_id: &str) -> Result<BotProfile, SlackApiError> {
+        if let Some(bot) = self.bots.lock().expect("bot cache poisoned").get(bot_id) {
+            return Ok(bot.clone());
+        }
+        let res: BotInfoResponse = self
+            .api_get("bots.info", &serde_json::json!({ "bot": bot_id }))
+            .await?;
+        self.bots
+            .lock()
+            .expect("bot cache poisoned")
+            .insert(bot_id.to_owned(), res.bot.clone());
+        Ok(res.bot)
+    }
+
+    /// Returns the workspace's custom emoji, mapping each name to its image URL, or to
+    /// `alias:<name>` for aliases. Cached for an hour.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_emoji(&self) -> Result<HashMap<String, String>, SlackApiError> {
+        if let Some((fetched, emoji)) = &*self.emoji.lock().expect("emoji cache poisoned") {
+            if fetched.elapsed() < EMOJI_CACHE_TTL {
+                return Ok(emoji.clone());
+            }
+        }
+        let res: EmojiListResponse = self.api_get("emoji.list", &serde_json::json!({})).await?;
+        *self.emoji.lock().expect("emoji cache poisoned") =
+            Some((Instant::now(), res.emoji.clone()));
+        Ok(res.emoji)
+    }
+
+    /// Lists every member of `channel`, fetching `limit` members per request until the cursor runs out.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn channel_members(
+        &self,
+        channel: &str,
+        limit: u32,
+    ) -> Result<Vec<UserId>, SlackApiError> {
+        self.channel_members_stream(channel, limit)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::channel_members`], but yields members as pages arrive.
+    pub fn channel_members_stream<'a>(
+        &'a self,
+        channel: &'a str,
+        limit: u32,
+    ) -> Paginator<'a, UserId> {
+        Paginator::new(move |cursor| async move {
+            let res: ChannelMembersResponse = self
+                .api_get(
+                    "conversations.members",
+                    &serde_json::json!({
+                        "channel": channel,
+                        "limit": limit,
+                        "cursor": cursor,
+                    }),
+                )
+                .await?;
+            Ok(Page {
+                items: res.members,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Fetches a whole thread. The parent message comes first, followed by the replies in order.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn thread_replies(
+        &self,
+        channel: &str,
+        thread_ts: &str,
+    ) -> Result<Vec<Message>, SlackApiError> {
+        self.thread_replies_stream(channel, thread_ts)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::thread_replies`], but yields messages as pages arrive.
+    pub fn thread_replies_stream<'a>(
+        &'a self,
+        channel: &'a str,
+        thread_ts: &'a str,
+    ) -> Paginator<'a, Message> {
+        Paginator::new(move |cursor| async move {
+            let res: ThreadRepliesResponse = self
+                .api_get(
+                    "conversations.replies",
+                    &serde_json::json!({
+                        "channel": channel,
+                        "ts": thread_ts,
+                        "limit": PAGE_SIZE,
+                        "cursor": cursor,
+                    }),
+                )
+                .await?;
+            Ok(Page {
+                items: res.messages,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Lists the conversations this token can see. `types` filters by kind, e.g.
+    /// `["public_channel", "private_channel"]`; Slack lists only public channels if it's empty.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_conversations(
+        &self,
+        types: &[&str],
+        exclude_archived: bool,
+    ) -> Result<Vec<Channel>, SlackApiError> {
+        self.list_conversations_stream(types, exclude_archived)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::list_conversations`], but yields channels as pages arrive.
+    pub fn list_conversations_stream<'a>(
+        &'a self,
+        types: &'a [&'a str],
+        exclude_archived: bool,
+    ) -> Paginator<'a, Channel> {
+        Paginator::new(move |cursor| async move {
+            let mut params = serde_json::json!({
+                "exclude_archived": exclude_archived,
+                "limit": PAGE_SIZE,
+                "cursor": cursor,
+            });
+            if !types.is_empty() {
+                params["types"] = types.join(",").into();
+            }
+            let res: ConversationsListResponse =
+                self.api_get("conversations.list", &params).await?;
+            Ok(Page {
+                items: res.channels,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Fetches a channel's messages, newest first. Long-lived channels can have a lot of them,
+    /// so prefer [`Self::conversation_history_stream`] unless the whole history is needed.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn conversation_history(&self, channel: &str) -> Result<Vec<Message>, SlackApiError> {
+        self.conversation_history_stream(channel)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::conversation_history`], but yields messages as pages arrive.
+    pub fn conversation_history_stream<'a>(&'a self, channel: &'a str) -> Paginator<'a, Message> {
+        Paginator::new(move |cursor| async move {
+            let res: ConversationsHistoryResponse = self
+                .api_get(
+                    "conversations.history",
+                    &serde_json::json!({
+                        "channel": channel,
+                        "limit": PAGE_SIZE,
+                        "cursor": cursor,
+                    }),
+                )
+                .await?;
+            Ok(Page {
+                items: res.messages,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Lists every user in the workspace, including deactivated ones and bots.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_users(&self) -> Result<Vec<User>, SlackApiError> {
+        self.list_users_stream().collect_all().await
+    }
+
+    /// Like [`Self::list_users`], but yields users as pages arrive.
+    pub fn list_users_stream(&self) -> Paginator<'_, User> {
+        Paginator::new(move |cursor| async move {
+            let res: UsersListResponse = self
+                .api_get(
+                    "users.list",
+                    &serde_json::json!({
+                        "limit": PAGE_SIZE,
+                        "cursor": cursor,
+                    }),
+                )
+                .await?;
+            Ok(Page {
+                items: res.members,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Lists the reactions on a message, with every reacting user rather than Slack's truncated list.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn get_reactions(
+        &self,
+        channel: &str,
+        ts: &str,
+    ) -> Result<Vec<Reaction>, SlackApiError> {
+        let res: ReactionsGetResponse = self
+            .api_get(
+                "reactions.get",
+                &serde_json::json!({
+                    "channel": channel,
+                    "timestamp": ts,
+                    "full": true,
+                }),
+            )
+            .await?;
+        Ok(res.message.reactions)
+    }
+
+    /// Creates a channel. `name` must follow Slack's rules, checked before sending: at most 80
+    /// characters of lowercase letters, digits, hyphens and underscores. Names already in use fail
+    /// with [`SlackApiError::NameTaken`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn create_channel(
+        &self,
+        name: &str,
+        is_private: bool,
+    ) -> Result<Channel, SlackApiError> {
+        let valid = !name.is_empty()
+            && name.chars().count() <= MAX_CHANNEL_NAME_LEN
+            && name
+                .chars()
+                .all(|c| (c.is_alphanumeric() && !c.is_uppercase()) || c == '-' || c == '_');
+        if !valid {
+            return Err(SlackApiError::InvalidChannelName {
+                name: name.to_owned(),
+            });
+        }
+        let res: ChannelInfoResponse = self
+            .api_post(
+                "conversations.create",
+                &serde_json::json!({ "name": name, "is_private": is_private }),
+            )
+            .await?;
+        Ok(res.channel)
+    }
+
+    /// Adds users to a channel. Users already in it are skipped rather than failing the call;
+    /// including the caller fails with [`SlackApiError::CantInviteSelf`], after the other users
+    /// have been invited.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn invite_to_channel(
+        &self,
+        channel: &str,
+        users: &[&str],
+    ) -> Result<(), SlackApiError> {
+        let res = self
+            .api_post::<InviteResponse>(
+                "conversations.invite",
+                &serde_json::json!({
+                    "channel": channel,
+                    "users": users.join(","),
+                    // Invite the valid users even if some of the others fail.
+                    "force": true,
+                }),
+            )
+            .await;
+        match res {
+            Ok(res) => {
+                let mut invited_self = false;
+                for failure in res.errors {
+                    match failure.error.as_str() {
+                        "already_in_channel" => {}
+                        // With `force`, this comes back here instead of failing the call.
+                        "cant_invite_self" => invited_self = true,
+                        _ => warn!("Failed to invite {}: {}", failure.user, failure.error),
+                    }
+                }
+                if invited_self {
+                    return Err(SlackApiError::CantInviteSelf);
+                }
+                Ok(())
+            }
+            // Every user was already a member.
+            Err(SlackApiError::Api { error, .. }) if error == "already_in_channel" => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Sets a channel's topic and returns it as stored. Topics are capped at 250 characters;
+    /// longer ones fail with [`SlackApiError::TooLong`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_topic(&self, channel: &str, topic: &str) -> Result<String, SlackApiError> {
+        let res: ChannelInfoResponse = self
+            .api_post(
+                "conversations.setTopic",
+                &serde_json::json!({ "channel": channel, "topic": topic }),
+            )
+            .await?;
+        Ok(res
+            .channel
+            .topic
+            .map_or_else(|| topic.to_owned(), |t| t.value))
+    }
+
+    /// Sets a channel's purpose and returns it as stored. Same 250 character cap as topics.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn set_purpose(&self, channel: &str, purpose: &str) -> Result<String, SlackApiError> {
+        let res: ChannelInfoResponse = self
+            .api_post(
+                "conversations.setPurpose",
+                &serde_json::json!({ "channel": channel, "purpose": purpose }),
+            )
+            .await?;
+        Ok(res
+            .channel
+            .purpose
+            .map_or_else(|| purpose.to_owned(), |p| p.value))
+    }
+
+    /// Lists the workspace's user groups along with their members. Tokens without
+    /// `usergroups:read` fail with [`SlackApiError::NoPermission`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_usergroups(&self) -> Result<Vec<Usergroup>, SlackApiError> {
+        let res: UsergroupsListResponse = self
+            .api_get(
+                "usergroups.list",
+                &serde_json::json!({ "include_users": true }),
+            )
+            .await?;
+        Ok(res.usergroups)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn usergroup_members(
+        &self,
+        usergroup_id: &str,
+    ) -> Result<Vec<UserId>, SlackApiError> {
+        let res: UsergroupUsersResponse = self
+            .api_get(
+                "usergroups.users.list",
+                &serde_json::json!({ "usergroup": usergroup_id }),
+            )
+            .await?;
+        Ok(res.users)
+    }
+
+    /// Fetches the workspace this client's token belongs to.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn team_info(&self) -> Result<Team, SlackApiError> {
+        let res: TeamInfoResponse = self.api_get("team.info", &serde_json::json!({})).await?;
+        Ok(res.team)
+    }
+
+    /// Lists the users of an Enterprise Grid workspace.
+    ///
+    /// Like the other `admin_*` methods, this needs a user token from an org admin or owner with
+    /// the `admin.users:read` scope; other tokens get [`SlackApiError::NotAllowedTokenType`] or
+    /// a `not_an_admin` [`SlackApiError::Api`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn admin_list_users(
+        &self,
+        team_id: &str,
+        limit: u32,
+    ) -> Result<Vec<AdminUserInfo>, SlackApiError> {
+        self.admin_list_users_stream(team_id, limit)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::admin_list_users`], but yields users as pages arrive.
+    pub fn admin_list_users_stream<'a>(
+        &'a self,
+        team_id: &'a str,
+        limit: u32,
+    ) -> Paginator<'a, AdminUserInfo> {
+        Paginator::new(move |cursor| async move {
+            let res: AdminUsersListResponse = self
+                .api_post(
+                    "admin.users.list",
+                    &serde_json::json!({
+                        "team_id": team_id,
+                        "cursor": cursor,
+                        "limit": limit,
+                    }),
+                )
+                .await?;
+            Ok(Page {
+                items: res.users,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Invites `email` to an Enterprise Grid workspace and the given channels.
+    /// Needs an org admin token with `admin.users:write`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn admin_invite_user(
+        &self,
+        email: &str,
+        team_id: &str,
+        channel_ids: &[&str],
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "admin.users.invite",
+            &serde_json::json!({
+                "email": email,
+                "team_id": team_id,
+                "channel_ids": channel_ids.join(","),
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Searches channels across an Enterprise Grid org, optionally limited to `team_ids`.
+    /// Needs an org admin token with `admin.conversations:read`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn admin_search_conversations(
+        &self,
+        query: &str,
+        team_ids: &[&str],
+        sort: ConvSort,
+    ) -> Result<Vec<AdminConversation>, SlackApiError> {
+        self.admin_search_conversations_stream(query, team_ids, sort)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::admin_search_conversations`], but yields channels as pages arrive.
+    pub fn admin_search_conversations_stream<'a>(
+        &'a self,
+        query: &'a str,
+        team_ids: &'a [&'a str],
+        sort: ConvSort,
+    ) -> Paginator<'a, AdminConversation> {
+        Paginator::new(move |cursor| async move {
+            let res: AdminConvSearchResponse = self
+                .api_post(
+                    "admin.conversations.search",
+                    &serde_json::json!({
+                        "query": query,
+                        "team_ids": team_ids.join(","),
+                        "sort": sort,
+                        "cursor": cursor,
+                    }),
+                )
+                .await?;
+            let next_cursor = if res.next_cursor.is_empty() {
+                res.response_metadata.next_cursor
+            } else {
+                res.next_cursor
+            };
+            Ok(Page {
+                items: res.conversations,
+                next_cursor,
+            })
+        })
+    }
+
+    /// Needs an org admin token with `admin.conversations:write`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn admin_archive_conversation(&self, channel_id: &str) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "admin.conversations.archive",
+            &serde_json::json!({ "channel_id": channel_id }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Needs an org admin token with `admin.conversations:write`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn admin_unarchive_conversation(
+        &self,
+        channel_id: &str,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "admin.conversations.unarchive",
+            &serde_json::json!({ "channel_id": channel_id }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Lists the installations an event is visible to, given the `event_context` from its
+    /// envelope. Only org-wide apps need this, and it must be called with an app-level token
+    /// (`authorizations:read`), i.e. a `SlackClient` built from the `xapp-` token.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn list_event_authorizations(
+        &self,
+        event_context: &str,
+        limit: u32,
+    ) -> Result<Vec<EventAuthorization>, SlackApiError> {
+        self.list_event_authorizations_stream(event_context, limit)
+            .collect_all()
+            .await
+    }
+
+    /// Like [`Self::list_event_authorizations`], but yields authorizations as pages arrive.
+    pub fn list_event_authorizations_stream<'a>(
+        &'a self,
+        event_context: &'a str,
+        limit: u32,
+    ) -> Paginator<'a, EventAuthorization> {
+        Paginator::new(move |cursor| async move {
+            let res: EventAuthorizationsResponse = self
+                .api_post(
+                    "apps.event.authorizations.list",
+                    &serde_json::json!({
+                        "event_context": event_context,
+                        "cursor": cursor,
+                        "limit": limit,
+                    }),
+                )
+                .await?;
+            Ok(Page {
+                items: res.authorizations,
+                next_cursor: res.response_metadata.next_cursor,
+            })
+        })
+    }
+
+    /// Marks a step started by a `workflow_step_execute` event as done. `outputs` are keyed by
+    /// the output names declared when the step was configured.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn complete_workflow_step(
+        &self,
+        workflow_step_execute_id: &str,
+        outputs: HashMap<String, String>,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "workflows.stepCompleted",
+            &serde_json::json!({
+                "workflow_step_execute_id": workflow_step_execute_id,
+                "outputs": outputs,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn fail_workflow_step(
+        &self,
+        workflow_step_execute_id: &str,
+        error: WorkflowStepError,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "workflows.stepFailed",
+            &serde_json::json!({
+                "workflow_step_execute_id": workflow_step_execute_id,
+                "error": error,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Revokes this client's token. With `test` set, Slack only reports whether it would be revoked.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn revoke_token(&self, test: bool) -> Result<RevokeResponse, SlackApiError> {
+        self.api_get("auth.revoke", &serde_json::json!({ "test": test }))
+            .await
+    }
+
+    /// Opens a modal and returns its view ID. Views that fail [`View::validate`] are rejected
+    /// with [`SlackApiError::InvalidView`] before anything is sent.
+    ///
+    /// `trigger_id` comes from an interactive payload and expires 3 seconds after it was issued,
+    /// so call this before doing any slow work in the handler.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn open_view(&self, trigger_id: &str, view: &View) -> Result<String, SlackApiError> {
+        view.validate().map_err(SlackApiError::InvalidView)?;
+        let res: ViewResponse = self
+            .api_post(
+                "views.open",
+                &serde_json::json!({
+                    "trigger_id": trigger_id,
+                    "view": view,
+                }),
+            )
+            .await?;
+        Ok(res.view.id)
+    }
+
+    /// Replaces the contents of an open view, e.g. to advance a multi-step modal in place.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn update_view(&self, view_id: &str, view: &View) -> Result<String, SlackApiError> {
+        view.validate().map_err(SlackApiError::InvalidView)?;
+        let res: ViewResponse = self
+            .api_post(
+                "views.update",
+                &serde_json::json!({
+                    "view_id": view_id,
+                    "view": view,
+                }),
+            )
+            .await?;
+        Ok(res.view.id)
+    }
+
+    /// Pushes a new view onto the modal stack. Same 3 second `trigger_id` window as [`Self::open_view`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn push_view(&self, trigger_id: &str, view: &View) -> Result<String, SlackApiError> {
+        view.validate().map_err(SlackApiError::InvalidView)?;
+        let res: ViewResponse = self
+            .api_post(
+                "views.push",
+                &serde_json::json!({
+                    "trigger_id": trigger_id,
+                    "view": view,
+                }),
+            )
+            .await?;
+        Ok(res.view.id)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn send_message(
+        &self,
+        channel: &str,
+        text: &str,
+    ) -> Result<PostMessageResponse, SlackApiError> {
+        self.send_message_with_options(channel, text, &PostOptions::default())
+            .await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn send_message_with_options(
+        &self,
+        channel: &str,
+        text: &str,
+        options: &PostOptions,
+    ) -> Result<PostMessageResponse, SlackApiError> {
+        self.post_message(channel, &MessageBody::new(text), options)
+            .await
+    }
+
+    /// Replies in the thread started by `thread_ts`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, body)))]
+    pub async fn send_message_to_thread(
+        &self,
+        channel: &str,
+        thread_ts: &str,
+        body: impl Into<MessageBody>,
+    ) -> Result<PostMessageResponse, SlackApiError> {
+        self.send_message_to_thread_with_broadcast(channel, thread_ts, body, false)
+            .await
+    }
+
+    /// Like [`Self::send_message_to_thread`]; with `reply_broadcast` the reply also shows up in
+    /// the channel itself.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, body)))]
+    pub async fn send_message_to_thread_with_broadcast(
+        &self,
+        channel: &str,
+        thread_ts: &str,
+        body: impl Into<MessageBody>,
+        reply_broadcast: bool,
+    ) -> Result<PostMessageResponse, SlackApiError> {
+        let options = PostOptions {
+            thread_ts: Some(thread_ts.to_owned()),
+            reply_broadcast: reply_broadcast.then_some(true),
+            ..PostOptions::default()
+        };
+        self.post_message(channel, &body.into(), &options).await
+    }
+
+    /// Posts a message with blocks or attachments. [`Self::send_message`] and friends are
+    /// shorthands for this.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn post_message(
+        &self,
+        channel: &str,
+        body: &MessageBody,
+        options: &PostOptions,
+    ) -> Result<PostMessageResponse, SlackApiError> {
+        if options.reply_broadcast == Some(true) && options.thread_ts.is_none() {
+            return Err(SlackApiError::BroadcastWithoutThread);
+        }
+        self.api_post(
+            "chat.postMessage",
+            &PostMessageRequest {
+                channel,
+                body,
+                options,
+            },
+        )
+        .await
+    }
+
+    /// Shows a message only to `user`, who must be in `channel`, and returns its `message_ts`.
+    /// Ephemeral messages don't persist across reloads and can't be updated via the Web API.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, body)))]
+    pub async fn send_ephemeral(
+        &self,
+        channel: &str,
+        user: &str,
+        body: impl Into<MessageBody>,
+    ) -> Result<String, SlackApiError> {
+        let res: PostEphemeralResponse = self
+            .api_post(
+                "chat.postEphemeral",
+                &PostEphemeralRequest {
+                    channel,
+                    user,
+                    body: &body.into(),
+                },
+            )
+            .await?;
+        Ok(res.message_ts)
+    }
+
+    /// Opens (or reuses) the DM with `user` and returns its channel ID.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn open_dm(&self, user: &str) -> Result<String, SlackApiError> {
+        let res: ConversationsOpenResponse = self
+            .api_post("conversations.open", &serde_json::json!({ "users": user }))
+            .await?;
+        Ok(res.channel.id)
+    }
+
+    /// Finds a user by email address. Needs the `users:read.email` scope; unknown addresses
+    /// fail with [`SlackApiError::UserNotFound`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, email)))]
+    pub async fn user_by_email(&self, email: &str) -> Result<User, SlackApiError> {
+        let res: LookupByEmailResponse = self
+            .api_get(
+                "users.lookupByEmail",
+                &serde_json::json!({ "email": email }),
+            )
+            .await?;
+        Ok(res.user)
+    }
+
+    /// DMs the user with this email address. Unknown addresses fail with
+    /// [`SlackApiError::UserNotFound`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, email)))]
+    pub async fn message_user_by_email(
+        &self,
+        email: &str,
+        text: &str,
+    ) -> Result<PostMessageResponse, SlackApiError> {
+        let user = self.user_by_email(email).await?;
+        let channel = self.open_dm(&user.id).await?;
+        self.send_message(&channel, text).await
+    }
+
+    /// Returns a link to the message, e.g. for cross-posting. Unknown messages fail with
+    /// [`SlackApiError::MessageNotFound`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn permalink(&self, channel: &str, ts: &str) -> Result<String, SlackApiError> {
+        let res: PermalinkResponse = self
+            .api_get(
+                "chat.getPermalink",
+                &serde_json::json!({ "channel": channel, "message_ts": ts }),
+            )
+            .await?;
+        Ok(res.permalink)
+    }
+
+    /// Replies to a slash command through its `response_url`. The reply is only visible to
+    /// the user who ran the command.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, response_url)))]
+    pub async fn respond_via_response_url(
+        &self,
+        response_url: &str,
+        text: &str,
+    ) -> Result<(), SlackApiError> {
+        self.respond(response_url, &ResponseUrlMessage::new(text))
+            .await
+    }
+
+    /// Posts `message` to a `response_url` from an interactive payload or slash command,
+    /// through this client's HTTP settings. Each URL accepts up to 5 responses within 30
+    /// minutes; a URL Slack no longer accepts fails with [`SlackApiError::Http`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self, response_url)))]
+    pub async fn respond(
+        &self,
+        response_url: &str,
+        message: &ResponseUrlMessage,
+    ) -> Result<(), SlackApiError> {
+        if self.dry_run {
+            return dry_run_response::<serde_json::Value>("response_url", message).map(|_| ());
+        }
+        let res = self.response_url_client(response_url).post(message).await?;
+        if !res.ok {
+            return Err(SlackApiError::Api {
+                method: "response_url",
+                error: res.error.unwrap_or_default(),
+            });
+        }
+        Ok(())
+    }
+
+    /// A [`ResponseUrlClient`] that shares this client's HTTP connection pool, proxy and
+    /// User-Agent.
+    pub fn response_url_client(&self, response_url: &str) -> ResponseUrlClient {
+        ResponseUrlClient::with_http(response_url, self.http.clone())
+    }
+
+    /// Attaches previews to the links in a message, keyed by URL, in reply to a `link_shared`
+    /// event. With `user_auth_required`, Slack instead asks the poster to authenticate with the
+    /// app, showing `user_auth_message` if given.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn unfurl_links(
+        &self,
+        channel: &str,
+        ts: &str,
+        unfurls: HashMap<String, Unfurl>,
+        user_auth_required: bool,
+        user_auth_message: Option<&str>,
+    ) -> Result<(), SlackApiError> {
+        let mut body = serde_json::json!({
+            "channel": channel,
+            "ts": ts,
+            "unfurls": unfurls,
+        });
+        if user_auth_required {
+            body["user_auth_required"] = true.into();
+        }
+        if let Some(message) = user_auth_message {
+            body["user_auth_message"] = message.into();
+        }
+        self.api_post::<serde_json::Value>("chat.unfurl", &body)
+            .await?;
+        Ok(())
+    }
+
+    /// Schedules `text` to be posted at `post_at` (unix seconds) and returns the
+    /// `scheduled_message_id`, which [`Self::delete_scheduled_message`] takes.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn schedule_message(
+        &self,
+        channel: &str,
+        post_at: i64,
+        text: &str,
+    ) -> Result<String, SlackApiError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs() as i64);
+        if post_at <= now || post_at > now + MAX_SCHEDULE_AHEAD.as_secs() as i64 {
+            return Err(SlackApiError::InvalidPostAt { post_at });
+        }
+        let res: ScheduleMessageResponse = self
+            .api_post(
+                "chat.scheduleMessage",
+                &serde_json::json!({
+                    "channel": channel,
+                    "post_at": post_at,
+                    "text": text,
+                }),
+            )
+            .await?;
+        Ok(res.scheduled_message_id)
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn delete_scheduled_message(
+        &self,
+        channel: &str,
+        scheduled_message_id: &str,
+    ) -> Result<(), SlackApiError> {
+        self.api_post::<serde_json::Value>(
+            "chat.deleteScheduledMessage",
+            &serde_json::json!({
+                "channel": channel,
+                "scheduled_message_id": scheduled_message_id,
+            }),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Per-workspace clients for an app installed in several workspaces, keyed by team ID.
+#[derive(Default)]
+pub struct WorkspaceRegistry {
+    clients: HashMap<String, SlackClient>,
+}
+
+impl WorkspaceRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the workspace's client, typically built with [`SlackClient::builder`] so it shares
+    /// the app's proxy, metrics and other settings.
+    pub fn register(&mut self, team_id: impl Into<String>, client: SlackClient) {
+        self.clients.insert(team_id.into(), client);
+    }
+
+    /// Shorthand for registering a [`SlackClient::new`] client with default settings.
+    pub fn register_token(&mut self, team_id: impl Into<String>, token: impl Into<String>) {
+        self.register(team_id, SlackClient::new(token));
+    }
+
+    pub fn unregister(&mut self, team_id: &str) -> Option<SlackClient> {
+        self.clients.remove(team_id)
+    }
+
+    pub fn client_for(&self, team_id: &str) -> Option<&SlackClient> {
+        self.clients.get(team_id)
+    }
+
+    pub fn team_ids(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_message_request_serializes_thread_broadcast() {
+        let body = MessageBody::new("hello");
+        let options = PostOptions {
+            thread_ts: Some("1700000000.000100".to_owned()),
+            reply_broadcast: Some(true),
+            ..PostOptions::default()
+        };
+        let request = PostMessageRequest {
+            channel: "C123",
+            body: &body,
+            options: &options,
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "channel": "C123",
+                "text": "hello",
+                "thread_ts": "1700000000.000100",
+                "reply_broadcast": true,
+            })
+        );
+    }
+
+    #[test]
+    fn post_message_request_omits_unset_thread_fields() {
+        let body = MessageBody::new("hello");
+        let request = PostMessageRequest {
+            channel: "C123",
+            body: &body,
+            options: &PostOptions::default(),
+        };
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({ "channel": "C123", "text": "hello" })
+        );
+    }
+
+    #[test]
+    fn broadcast_without_thread_is_rejected() {
+        let client = SlackClient::dry_run("xoxb-test");
+        let options = PostOptions {
+            reply_broadcast: Some(true),
+            ..PostOptions::default()
+        };
+        let result = async_std::task::block_on(client.post_message(
+            "C123",
+            &MessageBody::new("hello"),
+            &options,
+        ));
+        assert!(matches!(result, Err(SlackApiError::BroadcastWithoutThread)));
+    }
+
+    #[test]
+    fn user_agent_must_be_printable_ascii() {
+        assert!(SlackClient::builder("xoxb-test")
+            .user_agent("my-bot/1.0 (+https://example.com)")
+            .is_ok());
+        for user_agent in ["my-bot\r\nX-Injected: 1", "ボット/1.0"] {
+            assert!(matches!(
+                SlackClient::builder("xoxb-test").user_agent(user_agent),
+                Err(SlackApiError::InvalidUserAgent(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use serde::Deserialize;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    FileNotFound(std::path::PathBuf),
+    Io(std::io::Error),
+    ParseError(toml::de::Error),
+    MissingField(&'static str),
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FileNotFound(path) => write!(f, "Config file not found: {}", path.display()),
+            Self::Io(e) => write!(f, "Failed to read config file: {}", e),
+            Self::ParseError(e) => write!(f, "Failed to parse config file: {}", e),
+            Self::MissingField(field) => write!(f, "Missing config field: {}", field),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::ParseError(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct TomlConfig {
+    slack: Option<TomlSlackConfig>,
+}
+
+#[derive(Deserialize)]
+struct TomlSlackConfig {
+    app_level_token: Option<String>,
+    user_oauth_token: Option<String>,
+}
+
+pub struct RawConfig {
+    pub app_level_token: String,
+    pub user_oauth_token: String,
+    pub tls_connector: Option<async_tls::TlsConnector>,
+    pub proxy: Option<url::Url>,
+}
+
+impl RawConfig {
+    /// Reads tokens from the environment, falling back to the TOML file named by
+    /// `SLACK_CONFIG_FILE` when they are not set. With the `dotenv` feature, a `.env`
+    /// file is loaded into the environment first; variables already set take precedence.
+    pub fn from_env() -> Self {
+        #[cfg(feature = "dotenv")]
+        let _ = dotenvy::dotenv();
+        Self::from_vars(|key| std::env::var(key).ok())
+    }
+
+    /// [`Self::from_env`] with the environment lookup passed in.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let app_level_token_key = "SLACK_APP_LEVEL_TOKEN";
+        let user_oauth_token_key = "SLACK_USER_OAUTH_TOKEN";
+        let (Some(app_level_token), Some(user_oauth_token)) =
+            (var(app_level_token_key), var(user_oauth_token_key))
+        else {
+            if let Some(path) = var("SLACK_CONFIG_FILE") {
+                let config = Self::from_toml_file(std::path::Path::new(&path))
+                    .unwrap_or_else(|e| panic!("Failed to load {}: {}", path, e));
+                return Self {
+                    proxy: proxy_from_vars(&var),
+                    ..config
+                };
+            }
+            panic!(
+                "Please set the environment variables {} and {}, or SLACK_CONFIG_FILE",
+                app_level_token_key, user_oauth_token_key
+            );
+        };
+        Self {
+            app_level_token,
+            user_oauth_token,
+            tls_connector: None,
+            proxy: proxy_from_vars(&var),
+        }
+    }
+
+    /// Loads tokens from the `[slack]` table of a TOML file:
+    ///
+    /// ```toml
+    /// [slack]
+    /// app_level_token = "xapp-***"
+    /// user_oauth_token = "xoxb-***"
+    /// ```
+    pub fn from_toml_file(path: &std::path::Path) -> Result<Self, ConfigError> {
+        let content = std::fs::read_to_string(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => ConfigError::FileNotFound(path.to_owned()),
+            _ => ConfigError::Io(e),
+        })?;
+        let slack = toml::from_str::<TomlConfig>(&content)
+            .map_err(ConfigError::ParseError)?
+            .slack
+            .ok_or(ConfigError::MissingField("slack"))?;
+        Ok(Self {
+            app_level_token: slack
+                .app_level_token
+                .ok_or(ConfigError::MissingField("slack.app_level_token"))?,
+            user_oauth_token: slack
+                .user_oauth_token
+                .ok_or(ConfigError::MissingField("slack.user_oauth_token"))?,
+            tls_connector: None,
+            proxy: None,
+        })
+    }
+}
+
+/// The first proxy URL set in the usual environment variables. Only `http` and `https` proxies
+/// are supported, since Socket Mode tunnels through them with `CONNECT`; anything else, such as
+/// a `socks5://` URL, is ignored with a warning, as is a value that doesn't parse.
+fn proxy_from_vars(var: impl Fn(&str) -> Option<String>) -> Option<url::Url> {
+    let (key, value) = ["HTTPS_PROXY", "https_proxy", "ALL_PROXY", "all_proxy"]
+        .into_iter()
+        .find_map(|key| var(key).filter(|v| !v.is_empty()).map(|v| (key, v)))?;
+    match url::Url::parse(&value) {
+        Ok(proxy) if matches!(proxy.scheme(), "http" | "https") => Some(proxy),
+        Ok(proxy) => {
+            warn!(
+                "Ignoring {}: unsupported proxy scheme {}",
+                key,
+                proxy.scheme()
+            );
+            None
+        }
+        Err(e) => {
+            warn!("Ignoring {}: invalid proxy URL: {}", key, e);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn vars<'a>(pairs: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        let vars: HashMap<_, _> = pairs.iter().copied().collect();
+        move |key| vars.get(key).map(|v| v.to_string())
+    }
+
+    /// Writes `content` to a file of its own under the temp dir, named after the test.
+    fn config_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "sandbox-rust-slack-api-{}-{}.toml",
+            name,
+            std::process::id()
+        ));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    const TOML: &str = r#"
+        [slack]
+        app_level_token = "xapp-file"
+        user_oauth_token = "xoxb-file"
+    "#;
+
+    #[test]
+    fn env_tokens_take_precedence_over_config_file() {
+        let path = config_file("env-precedence", TOML);
+        let config = RawConfig::from_vars(vars(&[
+            ("SLACK_APP_LEVEL_TOKEN", "xapp-env"),
+            ("SLACK_USER_OAUTH_TOKEN", "xoxb-env"),
+            ("SLACK_CONFIG_FILE", path.to_str().unwrap()),
+        ]));
+        assert_eq!(config.app_level_token, "xapp-env");
+        assert_eq!(config.user_oauth_token, "xoxb-env");
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn config_file_is_used_unless_both_tokens_are_set() {
+        let path = config_file("file-fallback", TOML);
+        let config = RawConfig::from_vars(vars(&[
+            ("SLACK_APP_LEVEL_TOKEN", "xapp-env"),
+            ("SLACK_CONFIG_FILE", path.to_str().unwrap()),
+            ("HTTPS_PROXY", "http://proxy.example.com:3128"),
+        ]));
+        assert_eq!(config.app_level_token, "xapp-file");
+        assert_eq!(config.user_oauth_token, "xoxb-file");
+        // The proxy still comes from the environment.
+        assert_eq!(
+            config.proxy.unwrap().as_str(),
+            "http://proxy.example.com:3128/"
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "SLACK_CONFIG_FILE")]
+    fn missing_tokens_without_config_file_panics() {
+        RawConfig::from_vars(vars(&[("SLACK_APP_LEVEL_TOKEN", "xapp-env")]));
+    }
+
+    #[test]
+    fn config_file_errors() {
+        let missing = std::env::temp_dir().join("sandbox-rust-slack-api-does-not-exist.toml");
+        assert!(matches!(
+            RawConfig::from_toml_file(&missing),
+            Err(ConfigError::FileNotFound(path)) if path == missing
+        ));
+        let path = config_file("invalid", "[slack");
+        assert!(matches!(
+            RawConfig::from_toml_file(&path),
+            Err(ConfigError::ParseError(_))
+        ));
+        std::fs::remove_file(path).unwrap();
+        let path = config_file("missing-field", "[slack]\napp_level_token = \"xapp\"\n");
+        assert!(matches!(
+            RawConfig::from_toml_file(&path),
+            Err(ConfigError::MissingField("slack.user_oauth_token"))
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn https_proxy_takes_precedence_over_all_proxy() {
+        let proxy = proxy_from_vars(vars(&[
+            ("ALL_PROXY", "http://all.example.com:8080"),
+            ("HTTPS_PROXY", "http://https.example.com:3128"),
+        ]));
+        assert_eq!(proxy.unwrap().host_str(), Some("https.example.com"));
+        let proxy = proxy_from_vars(vars(&[
+            ("HTTPS_PROXY", ""),
+            ("all_proxy", "https://all.example.com"),
+        ]));
+        assert_eq!(proxy.unwrap().host_str(), Some("all.example.com"));
+    }
+
+    #[test]
+    fn unusable_proxy_urls_are_ignored() {
+        assert!(
+            proxy_from_vars(vars(&[("HTTPS_PROXY", "socks5://proxy.example.com:1080")])).is_none()
+        );
+        assert!(proxy_from_vars(vars(&[("HTTPS_PROXY", "not a url")])).is_none());
+        assert!(proxy_from_vars(vars(&[])).is_none());
+    }
+}
@@ -0,0 +1,242 @@
+use std::time::Duration;
+
+use crate::blocks::ValidationError;
+
+#[derive(Debug)]
+pub enum SlackApiError {
+    Http(surf::Error),
+    Decode {
+        method: &'static str,
+        source: serde_json::Error,
+    },
+    ChannelNotFound,
+    UserNotFound,
+    ThreadNotFound,
+    MessageNotFound,
+    ViewNotFound,
+    ExpiredTriggerId,
+    InvalidPostAt {
+        post_at: i64,
+    },
+    BroadcastWithoutThread,
+    InvalidView(Vec<ValidationError>),
+    InvalidChannelName {
+        name: String,
+    },
+    NameTaken,
+    CantInviteSelf,
+    InvalidUserAgent(String),
+    TooLong {
+        method: &'static str,
+    },
+    NoPermission {
+        method: &'static str,
+    },
+    NotAllowedTokenType {
+        method: &'static str,
+    },
+    RateLimited {
+        method: &'static str,
+        retry_after: Duration,
+    },
+    Api {
+        method: &'static str,
+        error: String,
+    },
+}
+
+impl SlackApiError {
+    pub(crate) fn from_code(method: &'static str, code: &str) -> Self {
+        match code {
+            "channel_not_found" => Self::ChannelNotFound,
+            "users_not_found" | "user_not_found" => Self::UserNotFound,
+            "thread_not_found" => Self::ThreadNotFound,
+            "message_not_found" => Self::MessageNotFound,
+            "not_found" if method.starts_with("views.") => Self::ViewNotFound,
+            "expired_trigger_id" => Self::ExpiredTriggerId,
+            "name_taken" => Self::NameTaken,
+            "cant_invite_self" => Self::CantInviteSelf,
+            "too_long" => Self::TooLong { method },
+            "no_permission" => Self::NoPermission { method },
+            "not_allowed_token_type" => Self::NotAllowedTokenType { method },
+            _ => Self::Api {
+                method,
+                error: code.to_owned(),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for SlackApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Http(e) => write!(f, "HTTP error: {}", e),
+            Self::Decode { method, source } => {
+                write!(f, "{}: failed to decode response: {}", method, source)
+            }
+            Self::ChannelNotFound => write!(f, "channel not found"),
+            Self::UserNotFound => write!(f, "user not found"),
+            Self::ThreadNotFound => write!(f, "thread not found"),
+            Self::MessageNotFound => write!(f, "message not found"),
+            Self::ViewNotFound => write!(f, "view not found"),
+            Self::ExpiredTriggerId => write!(f, "trigger_id expired"),
+            Self::InvalidPostAt { post_at } => write!(
+                f,
+                "post_at {} must be in the future and at most 120 days ahead",
+                post_at
+            ),
+            Self::BroadcastWithoutThread => write!(f, "reply_broadcast requires thread_ts"),
+            Self::InvalidView(errors) => {
+                write!(f, "invalid view: ")?;
+                for (i, error) in errors.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", error)?;
+                }
+                Ok(())
+            }
+            Self::InvalidChannelName { name } => write!(
+                f,
+                "invalid channel name {:?}: use at most 80 lowercase letters, digits, hyphens and underscores",
+                name
+            ),
+            Self::NameTaken => write!(f, "a channel with this name already exists"),
+            Self::CantInviteSelf => write!(f, "the caller can't invite themselves"),
+            Self::InvalidUserAgent(user_agent) => {
+                write!(f, "invalid User-Agent {:?}: use printable ASCII only", user_agent)
+            }
+            Self::TooLong { method } => write!(f, "{}: value exceeds Slack's length limit", method),
+            Self::NoPermission { method } => {
+                write!(f, "{}: the token lacks permission for this call", method)
+            }
+            Self::NotAllowedTokenType { method } => {
+                write!(f, "{} is not allowed with this token type", method)
+            }
+            Self::RateLimited {
+                method,
+                retry_after,
+            } => write!(f, "{} rate limited, retry after {:?}", method, retry_after),
+            Self::Api { method, error } => write!(f, "{} failed: {}", method, error),
+        }
+    }
+}
+
+impl std::error::Error for SlackApiError {}
+
+impl From<surf::Error> for SlackApiError {
+    fn from(e: surf::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+#[derive(Debug)]
+pub enum SocketModeError {
+    OpenConnection(surf::Error),
+    OpenConnectionRejected(String),
+    InvalidUrl(String),
+    ProxyRejected(String),
+    InvalidUserAgent(String),
+    LinkDisabled,
+    IdleTimeout(Duration),
+    /// The server closed the connection with an unexpected close code. Codes 1008 and
+    /// 4000-4999 end [`crate::SocketModeClient::run`] with this error; others are retried with backoff.
+    Closed {
+        code: u16,
+        reason: String,
+    },
+    Decode {
+        raw: String,
+        source: serde_json::Error,
+    },
+    Io(std::io::Error),
+    /// Boxed, as `tungstenite::Error` is large enough to bloat every `Result` carrying this type.
+    WebSocket(Box<tungstenite::Error>),
+}
+
+impl std::fmt::Display for SocketModeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OpenConnection(e) => write!(f, "Failed to request apps.connections.open: {}", e),
+            Self::OpenConnectionRejected(e) => write!(f, "apps.connections.open failed: {}", e),
+            Self::InvalidUrl(url) => write!(f, "Invalid url: {}", url),
+            Self::ProxyRejected(status) => write!(f, "Proxy refused CONNECT: {}", status),
+            Self::InvalidUserAgent(user_agent) => {
+                write!(
+                    f,
+                    "Invalid User-Agent {:?}: use printable ASCII only",
+                    user_agent
+                )
+            }
+            Self::LinkDisabled => write!(f, "Socket Mode has been disabled for this app"),
+            Self::IdleTimeout(timeout) => write!(f, "No frame received for {:?}", timeout),
+            Self::Closed { code, reason } => {
+                write!(f, "Connection closed with code {}: {}", code, reason)
+            }
+            Self::Decode { raw, source } => {
+                write!(f, "Failed to decode text frame: {}: {}", source, raw)
+            }
+            Self::Io(e) => write!(f, "I/O error: {}", e),
+            Self::WebSocket(e) => write!(f, "WebSocket error: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SocketModeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Decode { source, .. } => Some(source),
+            Self::Io(e) => Some(e),
+            Self::WebSocket(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for SocketModeError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<tungstenite::Error> for SocketModeError {
+    fn from(e: tungstenite::Error) -> Self {
+        Self::WebSocket(Box::new(e))
+    }
+}
+
+/// Error type returned by event handlers.
+#[derive(Debug)]
+pub enum SlackError {
+    Api(SlackApiError),
+    SocketMode(SocketModeError),
+}
+
+impl std::fmt::Display for SlackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Api(e) => e.fmt(f),
+            Self::SocketMode(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SlackError {}
+
+impl From<SlackApiError> for SlackError {
+    fn from(e: SlackApiError) -> Self {
+        Self::Api(e)
+    }
+}
+
+impl From<SocketModeError> for SlackError {
+    fn from(e: SocketModeError) -> Self {
+        Self::SocketMode(e)
+    }
+}
+
+impl From<surf::Error> for SlackError {
+    fn from(e: surf::Error) -> Self {
+        Self::Api(SlackApiError::Http(e))
+    }
+}
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// A slash command invocation, as delivered in a `slash_commands` Socket Mode envelope.
+#[derive(Deserialize, Debug, Clone)]
+pub struct SlashCommandPayload {
+    pub command: String,
+    #[serde(default)]
+    pub text: String,
+    /// Accepts up to 5 responses within 30 minutes of the invocation.
+    pub response_url: String,
+    pub trigger_id: String,
+    pub channel_id: String,
+    pub channel_name: String,
+    pub user_id: String,
+    pub user_name: String,
+    pub team_id: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AppMentionEvent {
+    pub user: String,
+    /// The full message text, mention included, e.g. `<@U0LAN0Z89> hello`.
+    pub text: String,
+    pub channel: String,
+    pub ts: String,
+    /// Set when the mention is a reply in a thread.
+    pub thread_ts: Option<String>,
+    pub event_ts: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MemberJoinedChannelEvent {
+    pub user: String,
+    pub channel: String,
+    /// `C` for public channels, `G` for private ones.
+    pub channel_type: String,
+    pub team: String,
+    /// Missing when the user joined on their own.
+    pub inviter: Option<String>,
+    pub event_ts: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct MemberLeftChannelEvent {
+    pub user: String,
+    pub channel: String,
+    pub channel_type: String,
+    pub team: String,
+    pub event_ts: String,
+}
+
+/// A `reaction_added` or `reaction_removed` event.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionEvent {
+    pub user: String,
+    /// The emoji name, without colons.
+    pub reaction: String,
+    /// Author of the item reacted to. Missing for items without one, such as some file comments.
+    pub item_user: Option<String>,
+    pub item: ReactionItem,
+    pub event_ts: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReactionItem {
+    /// `message`, `file` or `file_comment`. `channel` and `ts` are only set for messages.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub channel: Option<String>,
+    pub ts: Option<String>,
+}
+
+/// Payload of the `workflow_step_execute` event, sent when a Workflow Builder run reaches one of
+/// the app's steps. Finish the step with [`crate::SlackClient::complete_workflow_step`] or
+/// [`crate::SlackClient::fail_workflow_step`].
+#[derive(Deserialize, Debug, Clone)]
+pub struct WorkflowStepExecuteEvent {
+    pub callback_id: String,
+    pub workflow_step: WorkflowStep,
+    pub event_ts: String,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WorkflowStep {
+    pub workflow_step_execute_id: String,
+    pub workflow_id: String,
+    pub workflow_instance_id: String,
+    pub step_id: String,
+    #[serde(default)]
+    pub inputs: HashMap<String, WorkflowStepInput>,
+    #[serde(default)]
+    pub outputs: Vec<WorkflowStepOutput>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WorkflowStepInput {
+    pub value: serde_json::Value,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WorkflowStepOutput {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_name: String,
+    pub label: String,
+}
@@ -0,0 +1,118 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::error::SlackError;
+use crate::security::{verify_slack_signature, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+use crate::socket_mode::{EventKind, ParsedEvent};
+
+const RETRY_NUM_HEADER: &str = "X-Slack-Retry-Num";
+const RETRY_REASON_HEADER: &str = "X-Slack-Retry-Reason";
+
+/// Receives events over the HTTP Events API, for deployments that cannot use Socket Mode.
+pub struct HttpEventsServer {
+    addr: String,
+    path: String,
+    signing_secret: String,
+}
+
+struct State<F> {
+    signing_secret: Arc<str>,
+    handler: Arc<F>,
+}
+
+impl<F> Clone for State<F> {
+    fn clone(&self) -> Self {
+        Self {
+            signing_secret: self.signing_secret.clone(),
+            handler: self.handler.clone(),
+        }
+    }
+}
+
+impl HttpEventsServer {
+    pub fn new(addr: impl Into<String>, signing_secret: impl Into<String>) -> Self {
+        Self {
+            addr: addr.into(),
+            path: "/slack/events".to_owned(),
+            signing_secret: signing_secret.into(),
+        }
+    }
+
+    /// Request path Slack is configured to POST to. Defaults to `/slack/events`.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = path.into();
+        self
+    }
+
+    /// Serves until the listener fails. Each event is acknowledged with `200 OK` before
+    /// `handler` runs, like [`crate::SocketModeClient::events`] does.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all, fields(addr = %self.addr)))]
+    pub async fn run<F, Fut>(self, handler: F) -> std::io::Result<()>
+    where
+        F: Fn(ParsedEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), SlackError>> + Send + 'static,
+    {
+        let mut app = tide::with_state(State {
+            signing_secret: self.signing_secret.into(),
+            handler: Arc::new(handler),
+        });
+        app.at(&self.path).post(handle_request::<F, Fut>);
+        app.listen(self.addr).await
+    }
+}
+
+async fn handle_request<F, Fut>(mut req: tide::Request<State<F>>) -> tide::Result
+where
+    F: Fn(ParsedEvent) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), SlackError>> + Send + 'static,
+{
+    let body = req.body_bytes().await?;
+    let header = |name: &str| {
+        req.header(name)
+            .map(|v| v.last().as_str().to_owned())
+            .unwrap_or_default()
+    };
+    if !verify_slack_signature(
+        req.state().signing_secret.as_bytes(),
+        &header(TIMESTAMP_HEADER),
+        &body,
+        &header(SIGNATURE_HEADER),
+    ) {
+        return Ok(tide::Response::new(tide::StatusCode::Unauthorized));
+    }
+
+    let payload: serde_json::Value = match serde_json::from_slice(&body) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Failed to parse event body: {}", e);
+            return Ok(tide::Response::new(tide::StatusCode::BadRequest));
+        }
+    };
+    match payload["type"].as_str() {
+        Some("url_verification") => Ok(tide::Response::builder(tide::StatusCode::Ok)
+            .body(serde_json::json!({ "challenge": payload["challenge"] }))
+            .build()),
+        Some("event_callback") => {
+            let handler = req.state().handler.clone();
+            let event = ParsedEvent {
+                typed: EventKind::from_events_api(
+                    payload["event_id"].as_str().unwrap_or_default().to_owned(),
+                    payload.clone(),
+                ),
+                raw: payload,
+                retry_num: header(RETRY_NUM_HEADER).parse().ok(),
+                retry_reason: Some(header(RETRY_REASON_HEADER)).filter(|r| !r.is_empty()),
+            };
+            async_std::task::spawn(async move {
+                if let Err(e) = handler(event).await {
+                    error!("Event handler failed: {}", e);
+                }
+            });
+            Ok(tide::Response::new(tide::StatusCode::Ok))
+        }
+        other => {
+            warn!("Unknown event body type: {:?}", other);
+            Ok(tide::Response::new(tide::StatusCode::Ok))
+        }
+    }
+}
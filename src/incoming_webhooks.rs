@@ -0,0 +1,87 @@
+use crate::client::{http_client, DEFAULT_USER_AGENT};
+
+const WEBHOOK_URL_PREFIX: &str = "https://hooks.slack.com/";
+
+#[derive(Debug)]
+pub enum IncomingWebhookError {
+    InvalidUrl,
+    Http(surf::Error),
+    Rejected { status: u16, body: String },
+}
+
+impl std::fmt::Display for IncomingWebhookError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidUrl => write!(f, "Webhook URL must start with {}", WEBHOOK_URL_PREFIX),
+            Self::Http(e) => write!(f, "HTTP error: {}", e),
+            Self::Rejected { status, body } => {
+                write!(f, "Webhook rejected the message ({}): {}", status, body)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IncomingWebhookError {}
+
+impl From<surf::Error> for IncomingWebhookError {
+    fn from(e: surf::Error) -> Self {
+        Self::Http(e)
+    }
+}
+
+/// Posts to the channel an incoming webhook was created for. No token is involved;
+/// the webhook URL itself is the credential, so keep it out of logs.
+pub struct IncomingWebhookClient {
+    url: String,
+    http: surf::Client,
+}
+
+impl IncomingWebhookClient {
+    pub fn new(webhook_url: &str) -> Result<Self, IncomingWebhookError> {
+        if !webhook_url.starts_with(WEBHOOK_URL_PREFIX) || url::Url::parse(webhook_url).is_err() {
+            return Err(IncomingWebhookError::InvalidUrl);
+        }
+        Ok(Self {
+            url: webhook_url.to_owned(),
+            http: http_client(None, DEFAULT_USER_AGENT),
+        })
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn post(&self, text: &str) -> Result<(), IncomingWebhookError> {
+        self.send(&serde_json::json!({ "text": text })).await
+    }
+
+    /// `text` is the fallback shown in notifications and by clients that can't render blocks.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn post_blocks(
+        &self,
+        text: &str,
+        blocks: &[serde_json::Value],
+    ) -> Result<(), IncomingWebhookError> {
+        self.send(&serde_json::json!({ "text": text, "blocks": blocks }))
+            .await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn post_attachments(
+        &self,
+        text: &str,
+        attachments: &[serde_json::Value],
+    ) -> Result<(), IncomingWebhookError> {
+        self.send(&serde_json::json!({ "text": text, "attachments": attachments }))
+            .await
+    }
+
+    async fn send(&self, body: &serde_json::Value) -> Result<(), IncomingWebhookError> {
+        let mut res = self.http.post(&self.url).body_json(body)?.await?;
+        if res.status().is_success() {
+            return Ok(());
+        }
+        // Webhooks answer with a plain-text error code such as `invalid_payload` or `channel_is_archived`.
+        Err(IncomingWebhookError::Rejected {
+            status: res.status().into(),
+            body: res.body_string().await?,
+        })
+    }
+}
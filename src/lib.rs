@@ -0,0 +1,46 @@
+#[macro_use]
+mod macros;
+
+pub mod assistant;
+pub mod audit_logs;
+pub mod blocks;
+pub mod bookmarks;
+pub mod calls;
+pub mod client;
+pub mod config;
+pub mod error;
+pub mod events;
+pub mod http_events;
+pub mod incoming_webhooks;
+pub mod metrics;
+pub mod oauth;
+pub mod pagination;
+pub mod response_url;
+pub mod security;
+pub mod socket_mode;
+
+pub use assistant::SuggestedPrompt;
+pub use audit_logs::{AuditEvent, AuditLogsClient, AuditLogsQuery, AuditLogsResponse};
+pub use blocks::{ValidationError, View, ViewType};
+pub use bookmarks::{Bookmark, BookmarkType, BookmarksListResponse};
+pub use calls::{AddCallRequest, Call, CallParticipant};
+pub use client::{
+    sanitize_for_mrkdwn, AdminConversation, AdminUserInfo, BotProfile, Channel, ChannelTopic,
+    ConvSort, EventAuthorization, Message, MessageBody, PostMessageResponse, PostOptions, Reaction,
+    SlackClient, SlackClientBuilder, Team, Unfurl, User, UserId, UserProfile, Usergroup,
+    WorkflowStepError, WorkspaceRegistry, DEFAULT_USER_AGENT, DRY_RUN_TS,
+};
+pub use config::{ConfigError, RawConfig};
+pub use error::{SlackApiError, SlackError, SocketModeError};
+pub use events::{
+    AppMentionEvent, MemberJoinedChannelEvent, MemberLeftChannelEvent, ReactionEvent, ReactionItem,
+    SlashCommandPayload, WorkflowStepExecuteEvent,
+};
+pub use incoming_webhooks::{IncomingWebhookClient, IncomingWebhookError};
+pub use pagination::{Page, Paginator};
+pub use response_url::{OkResponse, ResponseType, ResponseUrlClient, ResponseUrlMessage};
+pub use socket_mode::{
+    AckBuilder, AckPayload, BufferPolicy, DisconnectReason, EventKind, IntoAck,
+    MultiWorkspaceSocketModeClient, ParsedEvent, ShutdownHandle, SocketModeClient,
+    SocketModeClientBuilder, SocketModeCluster, SocketModeEvent,
+};
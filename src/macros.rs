@@ -0,0 +1,30 @@
+// Logging shims. Messages go to `tracing` when that feature is enabled, otherwise to the `log`
+// facade (on by default), otherwise nowhere. The disabled arm still type-checks the arguments so
+// callers don't trip unused-variable lints.
+
+macro_rules! log_at {
+    ($level:ident, $($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)*);
+        #[cfg(all(feature = "log", not(feature = "tracing")))]
+        log::$level!($($arg)*);
+        #[cfg(not(any(feature = "log", feature = "tracing")))]
+        let _ = format_args!($($arg)*);
+    }};
+}
+
+macro_rules! debug {
+    ($($arg:tt)*) => { log_at!(debug, $($arg)*) };
+}
+
+macro_rules! info {
+    ($($arg:tt)*) => { log_at!(info, $($arg)*) };
+}
+
+macro_rules! warn {
+    ($($arg:tt)*) => { log_at!(warn, $($arg)*) };
+}
+
+macro_rules! error {
+    ($($arg:tt)*) => { log_at!(error, $($arg)*) };
+}
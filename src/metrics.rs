@@ -0,0 +1,95 @@
+use std::time::Duration;
+
+/// Hooks for exporting client activity to a metrics backend. Every method defaults to a no-op,
+/// so implementations only override what they export.
+pub trait Metrics: Send + Sync {
+    fn record_request(&self, _endpoint: &str, _status: u16, _duration: Duration) {}
+    fn record_ws_reconnect(&self) {}
+    /// A Socket Mode envelope was delivered, after deduplication. `envelope_type` is e.g.
+    /// `events_api` or `slash_commands`.
+    fn record_event(&self, _envelope_type: &str) {}
+    /// An envelope was acked, `latency` after its frame arrived.
+    fn record_ack(&self, _latency: Duration) {}
+}
+
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {}
+
+#[cfg(feature = "metrics-prometheus")]
+pub struct PrometheusMetrics {
+    requests: prometheus::IntCounterVec,
+    request_duration: prometheus::HistogramVec,
+    ws_reconnects: prometheus::IntCounter,
+    events: prometheus::IntCounterVec,
+    ack_latency: prometheus::Histogram,
+}
+
+#[cfg(feature = "metrics-prometheus")]
+impl PrometheusMetrics {
+    /// Creates the collectors and registers them with `registry`.
+    pub fn new(registry: &prometheus::Registry) -> prometheus::Result<Self> {
+        let requests = prometheus::IntCounterVec::new(
+            prometheus::Opts::new("slack_api_requests_total", "Slack Web API requests"),
+            &["endpoint", "status"],
+        )?;
+        let request_duration = prometheus::HistogramVec::new(
+            prometheus::HistogramOpts::new(
+                "slack_api_request_duration_seconds",
+                "Slack Web API request latency",
+            ),
+            &["endpoint"],
+        )?;
+        let ws_reconnects = prometheus::IntCounter::new(
+            "slack_socket_mode_reconnects_total",
+            "Socket Mode reconnections",
+        )?;
+        let events = prometheus::IntCounterVec::new(
+            prometheus::Opts::new(
+                "slack_socket_mode_events_total",
+                "Socket Mode envelopes received",
+            ),
+            &["type"],
+        )?;
+        let ack_latency = prometheus::Histogram::with_opts(prometheus::HistogramOpts::new(
+            "slack_socket_mode_ack_latency_seconds",
+            "Time from receiving a Socket Mode envelope to acking it",
+        ))?;
+        registry.register(Box::new(requests.clone()))?;
+        registry.register(Box::new(request_duration.clone()))?;
+        registry.register(Box::new(ws_reconnects.clone()))?;
+        registry.register(Box::new(events.clone()))?;
+        registry.register(Box::new(ack_latency.clone()))?;
+        Ok(Self {
+            requests,
+            request_duration,
+            ws_reconnects,
+            events,
+            ack_latency,
+        })
+    }
+}
+
+#[cfg(feature = "metrics-prometheus")]
+impl Metrics for PrometheusMetrics {
+    fn record_request(&self, endpoint: &str, status: u16, duration: Duration) {
+        self.requests
+            .with_label_values(&[endpoint, &status.to_string()])
+            .inc();
+        self.request_duration
+            .with_label_values(&[endpoint])
+            .observe(duration.as_secs_f64());
+    }
+
+    fn record_ws_reconnect(&self) {
+        self.ws_reconnects.inc();
+    }
+
+    fn record_event(&self, envelope_type: &str) {
+        self.events.with_label_values(&[envelope_type]).inc();
+    }
+
+    fn record_ack(&self, latency: Duration) {
+        self.ack_latency.observe(latency.as_secs_f64());
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use async_std::stream::Stream;
+use futures_util::TryStreamExt;
+
+use crate::error::SlackApiError;
+
+/// One page of a cursor-paginated endpoint. An empty `next_cursor` marks the last page, as in
+/// Slack's `response_metadata`.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: String,
+}
+
+/// Walks a cursor-paginated endpoint, yielding items one by one and fetching the next page only
+/// once the current one is used up. The stream ends after the first error.
+pub struct Paginator<'a, T> {
+    inner: Pin<Box<dyn Stream<Item = Result<T, SlackApiError>> + Send + 'a>>,
+}
+
+impl<'a, T: Send + 'a> Paginator<'a, T> {
+    /// `fetch` is called with the cursor of the page to load, starting with an empty one.
+    pub fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: FnMut(String) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<Page<T>, SlackApiError>> + Send + 'a,
+    {
+        let state = (Some(String::new()), VecDeque::new(), fetch);
+        let inner =
+            futures_util::stream::unfold(state, |(mut cursor, mut buffer, mut fetch)| async move {
+                loop {
+                    if let Some(item) = buffer.pop_front() {
+                        return Some((Ok(item), (cursor, buffer, fetch)));
+                    }
+                    match fetch(cursor.take()?).await {
+                        Ok(page) => {
+                            buffer.extend(page.items);
+                            cursor = (!page.next_cursor.is_empty()).then_some(page.next_cursor);
+                        }
+                        Err(e) => return Some((Err(e), (None, buffer, fetch))),
+                    }
+                }
+            });
+        Self {
+            inner: Box::pin(inner),
+        }
+    }
+
+    /// Fetches every page and returns all items in order.
+    pub async fn collect_all(self) -> Result<Vec<T>, SlackApiError> {
+        self.try_collect().await
+    }
+}
+
+impl<T> Stream for Paginator<'_, T> {
+    type Item = Result<T, SlackApiError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use async_std::stream::StreamExt;
+
+    use super::*;
+
+    /// Serves `pages` in order and records the cursor each one was fetched with.
+    fn paginator(
+        pages: Vec<Result<Page<u32>, SlackApiError>>,
+        cursors: Arc<Mutex<Vec<String>>>,
+    ) -> Paginator<'static, u32> {
+        let mut pages = pages.into_iter();
+        Paginator::new(move |cursor| {
+            cursors.lock().unwrap().push(cursor);
+            let page = pages.next().expect("fetched past the last page");
+            async move { page }
+        })
+    }
+
+    fn page(items: Vec<u32>, next_cursor: &str) -> Result<Page<u32>, SlackApiError> {
+        Ok(Page {
+            items,
+            next_cursor: next_cursor.to_owned(),
+        })
+    }
+
+    #[test]
+    fn yields_items_of_every_page_in_order() {
+        let cursors = Arc::default();
+        let pages = vec![page(vec![1, 2], "a"), page(vec![], "b"), page(vec![3], "")];
+        let items = async_std::task::block_on(paginator(pages, Arc::clone(&cursors)).collect_all());
+        assert_eq!(items.unwrap(), vec![1, 2, 3]);
+        assert_eq!(*cursors.lock().unwrap(), vec!["", "a", "b"]);
+    }
+
+    #[test]
+    fn empty_next_cursor_ends_the_stream() {
+        let cursors = Arc::default();
+        // The second page would panic if fetched.
+        let pages = vec![page(vec![1], "")];
+        let items = async_std::task::block_on(paginator(pages, Arc::clone(&cursors)).collect_all());
+        assert_eq!(items.unwrap(), vec![1]);
+        assert_eq!(cursors.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn stream_ends_after_first_error() {
+        let cursors = Arc::default();
+        let pages = vec![
+            page(vec![1], "a"),
+            Err(SlackApiError::ChannelNotFound),
+            page(vec![2], ""),
+        ];
+        let mut stream = paginator(pages, Arc::clone(&cursors));
+        async_std::task::block_on(async {
+            assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+            assert!(matches!(
+                stream.next().await,
+                Some(Err(SlackApiError::ChannelNotFound))
+            ));
+            assert!(stream.next().await.is_none());
+        });
+        assert_eq!(cursors.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,112 @@
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::blocks::Block;
+use crate::client::{http_client, DEFAULT_USER_AGENT};
+
+/// Who sees a `response_url` reply.
+#[derive(Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    InChannel,
+    /// Only the user who triggered the interaction sees it. Slack's default.
+    #[default]
+    Ephemeral,
+}
+
+/// A reply posted to a `response_url`. With neither `replace_original` nor `delete_original`
+/// set, it is posted as a new message.
+#[derive(Serialize, Debug, Default)]
+pub struct ResponseUrlMessage {
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<serde_json::Value>,
+    pub response_type: ResponseType,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub replace_original: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub delete_original: bool,
+}
+
+impl ResponseUrlMessage {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn blocks(mut self, blocks: Vec<Block>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    pub fn attachments(mut self, attachments: Vec<serde_json::Value>) -> Self {
+        self.attachments = attachments;
+        self
+    }
+
+    pub fn response_type(mut self, response_type: ResponseType) -> Self {
+        self.response_type = response_type;
+        self
+    }
+
+    pub fn replace_original(mut self, replace_original: bool) -> Self {
+        self.replace_original = replace_original;
+        self
+    }
+
+    pub fn delete_original(mut self, delete_original: bool) -> Self {
+        self.delete_original = delete_original;
+        self
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct OkResponse {
+    pub ok: bool,
+    pub error: Option<String>,
+}
+
+/// Replies through the `response_url` of a slash command or interactive payload, without a
+/// token. Each URL accepts up to 5 responses within 30 minutes, and is itself the credential,
+/// so keep it out of logs.
+pub struct ResponseUrlClient {
+    url: String,
+    http: surf::Client,
+}
+
+impl ResponseUrlClient {
+    /// Uses an HTTP client shared by every `ResponseUrlClient` made this way. To go through a
+    /// proxy or send a custom User-Agent, get one from
+    /// [`crate::SlackClient::response_url_client`] instead.
+    pub fn new(response_url: &str) -> Self {
+        static HTTP: OnceLock<surf::Client> = OnceLock::new();
+        let http = HTTP.get_or_init(|| http_client(None, DEFAULT_USER_AGENT));
+        Self::with_http(response_url, http.clone())
+    }
+
+    pub(crate) fn with_http(response_url: &str, http: surf::Client) -> Self {
+        Self {
+            url: response_url.to_owned(),
+            http,
+        }
+    }
+
+    /// Fails with the response's status when Slack rejects the message, e.g. once the URL has
+    /// expired or been used up.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn post(&self, message: &ResponseUrlMessage) -> surf::Result<OkResponse> {
+        let mut res = self.http.post(&self.url).body_json(message)?.await?;
+        if !res.status().is_success() {
+            return Err(surf::Error::from_str(
+                res.status(),
+                res.body_string().await?,
+            ));
+        }
+        res.body_json().await
+    }
+}
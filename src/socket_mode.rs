@@ -0,0 +1,1741 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_std::io::{ReadExt, WriteExt};
+use async_std::stream::{Stream, StreamExt};
+use base64::Engine;
+use futures_util::future::Either;
+use futures_util::sink::SinkExt;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use tungstenite::client::IntoClientRequest;
+use tungstenite::protocol::frame::coding::CloseCode;
+
+use crate::blocks::View;
+use crate::client::{http_client, is_valid_user_agent, WorkspaceRegistry, DEFAULT_USER_AGENT};
+use crate::error::{SlackError, SocketModeError};
+use crate::events::{
+    AppMentionEvent, MemberJoinedChannelEvent, MemberLeftChannelEvent, ReactionEvent,
+    SlashCommandPayload, WorkflowStepExecuteEvent,
+};
+use crate::metrics::{Metrics, NoopMetrics};
+
+#[derive(Deserialize, Debug)]
+pub struct OpenConnectionsResponse {
+    pub ok: bool,
+    pub url: Option<String>,
+    pub error: Option<String>,
+}
+#[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+pub async fn open_connections(
+    http: &surf::Client,
+    token: &str,
+) -> surf::Result<OpenConnectionsResponse> {
+    http.post("https://slack.com/api/apps.connections.open")
+        .header(
+            surf::http::headers::AUTHORIZATION,
+            format!("Bearer {}", token),
+        )
+        .recv_json()
+        .await
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum DisconnectReason {
+    /// Sent shortly before the server closes the connection; the connection is still usable.
+    Warning,
+    RefreshRequested,
+    TooManyConnections,
+    LinkDisabled,
+    Other(String),
+}
+
+impl From<String> for DisconnectReason {
+    fn from(reason: String) -> Self {
+        match reason.as_str() {
+            "warning" => Self::Warning,
+            "refresh_requested" => Self::RefreshRequested,
+            "too_many_connections" => Self::TooManyConnections,
+            "link_disabled" => Self::LinkDisabled,
+            _ => Self::Other(reason),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(remote = "Self", rename_all = "snake_case", tag = "type")]
+pub enum SocketModeMessage {
+    Hello {},
+    Disconnect {
+        reason: DisconnectReason,
+    },
+    EventsApi {
+        envelope_id: String,
+        payload: serde_json::Value,
+        /// `0` and an empty reason on the first delivery.
+        #[serde(default)]
+        retry_attempt: u32,
+        #[serde(default)]
+        retry_reason: String,
+    },
+    SlashCommands {
+        envelope_id: String,
+        payload: serde_json::Value,
+    },
+    /// A message `type` this crate doesn't know yet, kept intact instead of failing to parse.
+    #[serde(skip)]
+    Unknown {
+        type_name: String,
+        raw: serde_json::Value,
+    },
+}
+
+impl<'de> Deserialize<'de> for SocketModeMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = serde_json::Value::deserialize(deserializer)?;
+        match raw.get("type").and_then(|v| v.as_str()) {
+            Some("hello" | "disconnect" | "events_api" | "slash_commands") => {
+                SocketModeMessage::deserialize(raw).map_err(serde::de::Error::custom)
+            }
+            Some(type_name) => Ok(Self::Unknown {
+                type_name: type_name.to_owned(),
+                raw,
+            }),
+            None => Err(serde::de::Error::missing_field("type")),
+        }
+    }
+}
+
+/// Payload of the `tokens_revoked` event: the user IDs whose tokens were revoked, by token type.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct RevokedTokens {
+    #[serde(default)]
+    pub oauth: Vec<String>,
+    #[serde(default)]
+    pub bot: Vec<String>,
+}
+
+/// Items yielded by [`SocketModeClient::events`]: data events plus connection state changes,
+/// so health checks can watch the latter without touching the former.
+#[derive(Debug, Clone)]
+pub enum SocketModeEvent {
+    Connected,
+    /// About to make connection attempt number `attempt` since the last successful connect.
+    Reconnecting {
+        attempt: u32,
+    },
+    /// `reason` is `None` when the connection dropped without a `disconnect` message.
+    Disconnected {
+        reason: Option<DisconnectReason>,
+    },
+    Event(ParsedEvent),
+}
+
+pub type TokenRefreshCallback = Arc<dyn Fn(&RevokedTokens) + Send + Sync>;
+
+/// An event along with the envelope it arrived in, so fields and event types this crate doesn't
+/// model yet can still be read from `raw`.
+#[derive(Debug, Clone)]
+pub struct ParsedEvent {
+    pub typed: EventKind,
+    pub raw: serde_json::Value,
+    /// How many times Slack has redelivered this event, `None` on the first delivery. Handlers
+    /// with side effects can check this to avoid repeating them.
+    pub retry_num: Option<u32>,
+    /// Why Slack redelivered the event, e.g. `timeout` or `http_error`.
+    pub retry_reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum EventKind {
+    EventsApi {
+        envelope_id: String,
+        payload: serde_json::Value,
+    },
+    /// An Events API `url_verification` request. The challenge has already been echoed in the ack.
+    UrlVerification { challenge: String },
+    /// A [`SocketModeClient::run`] handler can answer it through the ack, e.g. with
+    /// [`AckPayload::text_response`]; later replies go through the `response_url`, e.g. with
+    /// [`crate::SlackClient::respond_via_response_url`].
+    SlashCommand {
+        envelope_id: String,
+        payload: Box<SlashCommandPayload>,
+    },
+    ReactionAdded {
+        envelope_id: String,
+        event: Box<ReactionEvent>,
+    },
+    ReactionRemoved {
+        envelope_id: String,
+        event: Box<ReactionEvent>,
+    },
+    /// The app was @-mentioned. Mentions also arrive as `message` events if the app subscribes
+    /// to those.
+    AppMention {
+        envelope_id: String,
+        event: Box<AppMentionEvent>,
+    },
+    MemberJoinedChannel {
+        envelope_id: String,
+        event: Box<MemberJoinedChannelEvent>,
+    },
+    MemberLeftChannel {
+        envelope_id: String,
+        event: Box<MemberLeftChannelEvent>,
+    },
+    /// A Workflow Builder run reached one of the app's steps.
+    WorkflowStepExecute {
+        envelope_id: String,
+        event: Box<WorkflowStepExecuteEvent>,
+    },
+    /// An envelope type this crate doesn't model, such as `interactive`; its payload is in
+    /// [`ParsedEvent::raw`]. Envelopes with an `envelope_id` are acked like any other, so a
+    /// [`SocketModeClient::run`] handler can answer e.g. a `view_submission` through the ack.
+    Unknown {
+        envelope_id: Option<String>,
+        type_name: String,
+    },
+}
+
+impl EventKind {
+    /// Picks the typed variant for an Events API envelope, falling back to
+    /// [`EventKind::EventsApi`] for event types that aren't modelled or don't parse.
+    pub(crate) fn from_events_api(envelope_id: String, payload: serde_json::Value) -> Self {
+        if let ("url_verification", Some(challenge)) = (
+            payload["type"].as_str().unwrap_or_default(),
+            payload["challenge"].as_str(),
+        ) {
+            return EventKind::UrlVerification {
+                challenge: challenge.to_owned(),
+            };
+        }
+        let event = &payload["event"];
+        let typed = match event["type"].as_str() {
+            Some("reaction_added") => parse_event(event).map(|event| EventKind::ReactionAdded {
+                envelope_id: envelope_id.clone(),
+                event,
+            }),
+            Some("reaction_removed") => {
+                parse_event(event).map(|event| EventKind::ReactionRemoved {
+                    envelope_id: envelope_id.clone(),
+                    event,
+                })
+            }
+            Some("app_mention") => parse_event(event).map(|event| EventKind::AppMention {
+                envelope_id: envelope_id.clone(),
+                event,
+            }),
+            Some("member_joined_channel") => {
+                parse_event(event).map(|event| EventKind::MemberJoinedChannel {
+                    envelope_id: envelope_id.clone(),
+                    event,
+                })
+            }
+            Some("member_left_channel") => {
+                parse_event(event).map(|event| EventKind::MemberLeftChannel {
+                    envelope_id: envelope_id.clone(),
+                    event,
+                })
+            }
+            Some("workflow_step_execute") => {
+                parse_event(event).map(|event| EventKind::WorkflowStepExecute {
+                    envelope_id: envelope_id.clone(),
+                    event,
+                })
+            }
+            _ => None,
+        };
+        typed.unwrap_or(EventKind::EventsApi {
+            envelope_id,
+            payload,
+        })
+    }
+}
+
+impl ParsedEvent {
+    /// Wraps a message of a type this crate doesn't know, keeping the whole message in `raw`.
+    fn unknown(type_name: String, raw: serde_json::Value) -> Self {
+        let envelope_id = raw["envelope_id"].as_str().map(str::to_owned);
+        Self {
+            typed: EventKind::Unknown {
+                envelope_id,
+                type_name,
+            },
+            raw,
+            retry_num: None,
+            retry_reason: None,
+        }
+    }
+}
+
+impl EventKind {
+    /// The payload the envelope's ack has to carry whatever the handler does: the echoed
+    /// `challenge` of a `url_verification` request.
+    pub(crate) fn required_ack(&self) -> Option<AckPayload> {
+        match self {
+            EventKind::UrlVerification { challenge } => Some(AckPayload::Text(challenge.clone())),
+            _ => None,
+        }
+    }
+}
+
+fn parse_event<T: serde::de::DeserializeOwned>(event: &serde_json::Value) -> Option<Box<T>> {
+    match T::deserialize(event) {
+        Ok(event) => Some(Box::new(event)),
+        Err(e) => {
+            warn!("Failed to parse {} event: {}", event["type"], e);
+            None
+        }
+    }
+}
+
+/// What to send back with an ack. Interactive payloads (shortcuts, block actions, view
+/// submissions) expect `Json`, which is embedded as an object rather than a quoted string.
+#[derive(Serialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum AckPayload {
+    Text(String),
+    Json(serde_json::Value),
+}
+
+impl AckPayload {
+    /// Replies to a slash command with an ephemeral message.
+    pub fn text_response(text: &str) -> Self {
+        Self::Json(serde_json::json!({ "text": text }))
+    }
+
+    pub fn message_response(channel: &str, text: &str) -> Self {
+        Self::Json(serde_json::json!({
+            "channel": channel,
+            "text": text,
+        }))
+    }
+
+    /// Answers a `view_submission` by replacing the submitted view.
+    pub fn view_response(view: &View) -> Self {
+        Self::Json(serde_json::json!({
+            "response_action": "update",
+            "view": view,
+        }))
+    }
+}
+
+/// What a [`SocketModeClient::run`] handler returns: `()` for an empty ack, or the payload to
+/// ack the envelope with.
+pub trait IntoAck {
+    fn into_ack(self) -> Option<AckPayload>;
+}
+
+impl IntoAck for () {
+    fn into_ack(self) -> Option<AckPayload> {
+        None
+    }
+}
+
+impl IntoAck for AckPayload {
+    fn into_ack(self) -> Option<AckPayload> {
+        Some(self)
+    }
+}
+
+impl IntoAck for Option<AckPayload> {
+    fn into_ack(self) -> Option<AckPayload> {
+        self
+    }
+}
+
+#[derive(Serialize)]
+pub struct SocketModeAcknowledgeMessage<'s> {
+    pub envelope_id: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<AckPayload>,
+}
+
+/// Builds the ack for an envelope with the payload shape Socket Mode expects for common responses,
+/// for connections from [`SocketModeClient::connect`] driven by hand. [`SocketModeClient::run`]
+/// and [`SocketModeClient::events`] send acks themselves.
+pub struct AckBuilder<'s> {
+    envelope_id: &'s str,
+    payload: Option<AckPayload>,
+}
+
+impl<'s> AckBuilder<'s> {
+    pub fn new(envelope_id: &'s str) -> Self {
+        Self {
+            envelope_id,
+            payload: None,
+        }
+    }
+
+    /// See [`AckPayload::text_response`].
+    pub fn with_text_response(mut self, text: &str) -> Self {
+        self.payload = Some(AckPayload::text_response(text));
+        self
+    }
+
+    pub fn with_message_response(mut self, channel: &str, text: &str) -> Self {
+        self.payload = Some(AckPayload::message_response(channel, text));
+        self
+    }
+
+    /// See [`AckPayload::view_response`].
+    pub fn with_view_response(mut self, view: &View) -> Self {
+        self.payload = Some(AckPayload::view_response(view));
+        self
+    }
+
+    pub fn build(&self) -> SocketModeAcknowledgeMessage<'s> {
+        SocketModeAcknowledgeMessage {
+            envelope_id: self.envelope_id,
+            payload: self.payload.clone(),
+        }
+    }
+
+    pub async fn send_via(&self, stream: &mut WsStream) -> Result<(), SlackError> {
+        let ack = serde_json::to_string(&self.build()).expect("Failed to serialize ack message");
+        stream
+            .send(tungstenite::Message::Text(ack))
+            .await
+            .map_err(SocketModeError::from)?;
+        Ok(())
+    }
+}
+
+pub type WsStream =
+    async_tungstenite::WebSocketStream<async_tls::client::TlsStream<async_std::net::TcpStream>>;
+
+/// What the serve loop needs from a connection: [`WsStream`] in practice, an in-memory socket in
+/// tests.
+trait WsTransport:
+    Stream<Item = Result<tungstenite::Message, tungstenite::Error>>
+    + futures_util::Sink<tungstenite::Message, Error = tungstenite::Error>
+    + Unpin
+{
+}
+
+impl<T> WsTransport for T where
+    T: Stream<Item = Result<tungstenite::Message, tungstenite::Error>>
+        + futures_util::Sink<tungstenite::Message, Error = tungstenite::Error>
+        + Unpin
+{
+}
+
+const INITIAL_RECONNECT_DELAY: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_DELAY: Duration = Duration::from_secs(60);
+/// Reconnecting right after a `too_many_connections` disconnect only gets refused again.
+const TOO_MANY_CONNECTIONS_DELAY: Duration = Duration::from_secs(30);
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+const DEFAULT_ACK_WARNING_THRESHOLD: Duration = Duration::from_millis(2500);
+const DEFAULT_EVENT_BUFFER_SIZE: usize = 64;
+const CLOSE_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(5);
+const UNKNOWN_FRAME_LOG_INTERVAL: Duration = Duration::from_secs(60);
+const DEFAULT_DEDUP_CACHE_SIZE: usize = 1000;
+const DEFAULT_CLUSTER_CONNECTIONS: usize = 2;
+
+fn reconnect_delay(attempt: u32) -> Duration {
+    INITIAL_RECONNECT_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+        .min(MAX_RECONNECT_DELAY)
+}
+
+/// Whether reconnecting can help after the server closed the connection with `code`. Policy
+/// violations (1008) and application-defined codes (4000-4999) would only be repeated.
+fn is_recoverable_close(code: u16) -> bool {
+    code != 1008 && !(4000..5000).contains(&code)
+}
+
+async fn connect_via_proxy(
+    proxy: &url::Url,
+    domain: &str,
+) -> Result<async_std::net::TcpStream, SocketModeError> {
+    let proxy_host = proxy
+        .host_str()
+        .ok_or_else(|| SocketModeError::InvalidUrl(proxy.to_string()))?;
+    let proxy_port = proxy.port_or_known_default().unwrap_or(8080);
+    let mut stream = async_std::net::TcpStream::connect((proxy_host, proxy_port)).await?;
+
+    let mut request = format!("CONNECT {0}:443 HTTP/1.1\r\nHost: {0}:443\r\n", domain);
+    if !proxy.username().is_empty() {
+        let credentials = format!("{}:{}", proxy.username(), proxy.password().unwrap_or(""));
+        request.push_str(&format!(
+            "Proxy-Authorization: Basic {}\r\n",
+            base64::engine::general_purpose::STANDARD.encode(credentials)
+        ));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    let mut response = Vec::new();
+    let mut byte = [0u8; 1];
+    while !response.ends_with(b"\r\n\r\n") {
+        if stream.read(&mut byte).await? == 0 || response.len() > 8192 {
+            return Err(SocketModeError::ProxyRejected(
+                "unterminated CONNECT response".to_owned(),
+            ));
+        }
+        response.push(byte[0]);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    if status_line.split_whitespace().nth(1) != Some("200") {
+        return Err(SocketModeError::ProxyRejected(status_line.to_owned()));
+    }
+    Ok(stream)
+}
+
+/// A flag that, once set, wakes up everything waiting on it.
+#[derive(Clone, Default)]
+pub struct CancellationToken(Arc<CancellationState>);
+
+#[derive(Default)]
+struct CancellationState {
+    cancelled: AtomicBool,
+    event: event_listener::Event,
+}
+
+impl CancellationToken {
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::SeqCst);
+        self.0.event.notify(usize::MAX);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub async fn cancelled(&self) {
+        while !self.is_cancelled() {
+            let listener = self.0.event.listen();
+            if self.is_cancelled() {
+                return;
+            }
+            listener.await;
+        }
+    }
+}
+
+/// Stops a running [`SocketModeClient`] cleanly: the client sends a WebSocket close frame,
+/// waits for the server to acknowledge it, and `run` returns `Ok(())`.
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    token: CancellationToken,
+}
+
+impl ShutdownHandle {
+    pub fn shutdown(&self) {
+        self.token.cancel();
+    }
+}
+
+async fn close_gracefully(mut stream: impl WsTransport) -> Result<(), SocketModeError> {
+    stream.close().await?;
+    // The server echoes the close frame and then ends the stream.
+    let _ = async_std::future::timeout(CLOSE_HANDSHAKE_TIMEOUT, async {
+        while let Some(Ok(_)) = stream.next().await {}
+    })
+    .await;
+    Ok(())
+}
+
+/// Rate-limits log lines per key: the first occurrence is logged, then at most one line per
+/// [`UNKNOWN_FRAME_LOG_INTERVAL`] reporting how many were suppressed in between.
+#[derive(Default)]
+struct LogThrottle {
+    keys: HashMap<String, (Instant, u64)>,
+}
+
+impl LogThrottle {
+    /// Returns how many occurrences of `key` were suppressed since it was last logged, or `None`
+    /// if this one should be suppressed too.
+    fn check(&mut self, key: &str) -> Option<u64> {
+        self.check_at(key, Instant::now())
+    }
+
+    fn check_at(&mut self, key: &str, now: Instant) -> Option<u64> {
+        match self.keys.get_mut(key) {
+            None => {
+                self.keys.insert(key.to_owned(), (now, 0));
+                Some(0)
+            }
+            Some((logged, suppressed))
+                if now.saturating_duration_since(*logged) >= UNKNOWN_FRAME_LOG_INTERVAL =>
+            {
+                let count = *suppressed;
+                *logged = now;
+                *suppressed = 0;
+                Some(count)
+            }
+            Some((_, suppressed)) => {
+                *suppressed += 1;
+                None
+            }
+        }
+    }
+}
+
+/// LRU set of the last `capacity` envelope IDs, so redelivered envelopes (common around
+/// reconnects) are only dispatched once.
+pub struct EnvelopeDeduplicator {
+    capacity: usize,
+    seen: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl EnvelopeDeduplicator {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records `envelope_id` and returns `true` if it has not been seen recently.
+    pub fn insert(&mut self, envelope_id: &str) -> bool {
+        if self.capacity == 0 {
+            return true;
+        }
+        if self.seen.contains(envelope_id) {
+            // Duplicates are rare, so the linear scan to mark it as recently used is fine.
+            if let Some(pos) = self.order.iter().position(|id| id == envelope_id) {
+                let id = self.order.remove(pos).expect("position is in bounds");
+                self.order.push_back(id);
+            }
+            return false;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(envelope_id.to_owned());
+        self.order.push_back(envelope_id.to_owned());
+        true
+    }
+}
+
+/// What [`SocketModeClient::events`] does when its buffer is full because the consumer is
+/// falling behind.
+///
+/// Envelopes are acked as soon as they are read, before they're buffered, so neither policy
+/// delays the ack of the event that hits the full buffer. With `Block`, though, the connection
+/// stops reading until there's room, so envelopes queued behind it are acked late and Slack may
+/// redeliver them. `DropOldest` keeps acks prompt at the cost of losing events, which is only
+/// fine for bots that can afford to miss some.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BufferPolicy {
+    #[default]
+    Block,
+    /// Discards the oldest buffered event, with a warning, to make room for the new one.
+    DropOldest,
+}
+
+pub struct SocketModeClient {
+    app_level_token: String,
+    http: surf::Client,
+    proxy: Option<url::Url>,
+    tls_connector: async_tls::TlsConnector,
+    idle_timeout: Duration,
+    ack_warning_threshold: Duration,
+    token_refresh_callback: Option<TokenRefreshCallback>,
+    metrics: Arc<dyn Metrics>,
+    shutdown: CancellationToken,
+    dedup: Arc<Mutex<EnvelopeDeduplicator>>,
+    rng: Mutex<StdRng>,
+    event_buffer_size: usize,
+    buffer_policy: BufferPolicy,
+    /// Connections still running in this client's cluster, if it belongs to one.
+    live_connections: Option<Arc<AtomicUsize>>,
+    unknown_frames: Mutex<LogThrottle>,
+    user_agent: String,
+}
+
+impl SocketModeClient {
+    pub fn builder(app_level_token: impl Into<String>) -> SocketModeClientBuilder {
+        SocketModeClientBuilder::new(app_level_token)
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            token: self.shutdown.clone(),
+        }
+    }
+
+    /// Opens a fresh Socket Mode connection: `apps.connections.open` → TCP → TLS → WebSocket.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self)))]
+    pub async fn connect(&self) -> Result<WsStream, SocketModeError> {
+        let con_result = open_connections(&self.http, &self.app_level_token)
+            .await
+            .map_err(SocketModeError::OpenConnection)?;
+        if !con_result.ok {
+            return Err(SocketModeError::OpenConnectionRejected(
+                con_result
+                    .error
+                    .unwrap_or_else(|| "Unknown error".to_owned()),
+            ));
+        }
+        let wss_url = con_result.url.ok_or_else(|| {
+            SocketModeError::OpenConnectionRejected("no url passed from server".to_owned())
+        })?;
+        let url =
+            url::Url::parse(&wss_url).map_err(|_| SocketModeError::InvalidUrl(wss_url.clone()))?;
+        let domain = url
+            .domain()
+            .ok_or_else(|| SocketModeError::InvalidUrl(wss_url.clone()))?;
+        let tcp_stream = match &self.proxy {
+            Some(proxy) => connect_via_proxy(proxy, domain).await?,
+            None => async_std::net::TcpStream::connect(&format!("{}:443", domain)).await?,
+        };
+        let enc_stream = self.tls_connector.connect(domain, tcp_stream).await?;
+        let mut request = wss_url.as_str().into_client_request()?;
+        request.headers_mut().insert(
+            tungstenite::http::header::USER_AGENT,
+            tungstenite::http::HeaderValue::from_str(&self.user_agent)
+                .expect("User-Agent is checked by is_valid_user_agent"),
+        );
+        let (stream, _) = async_tungstenite::client_async(request, enc_stream).await?;
+        Ok(stream)
+    }
+
+    /// Connects and dispatches events to `handler` until Slack disables the link or closes the
+    /// connection with a code that rules out reconnecting, reconnecting whenever the connection
+    /// drops or the server asks us to. Connections that keep failing are retried with backoff.
+    ///
+    /// Each envelope is acked once `handler` returns, with the payload it returned (see
+    /// [`IntoAck`]), or with an empty one if it failed. Slack redelivers envelopes that aren't
+    /// acked within 3 seconds, so slow work belongs in a spawned task.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn run<F, Fut, A>(&self, handler: F) -> Result<(), SocketModeError>
+    where
+        F: Fn(ParsedEvent) -> Fut,
+        Fut: Future<Output = Result<A, SlackError>>,
+        A: IntoAck,
+    {
+        let handler = &handler;
+        self.run_with(AckMode::AfterHandler, |item| async move {
+            match item {
+                Ok(SocketModeEvent::Event(event)) => match handler(event).await {
+                    Ok(ack) => return Flow::Continue(ack.into_ack()),
+                    Err(e) => error!("Event handler failed: {}", e),
+                },
+                Ok(_) => {}
+                // Already logged, rate-limited, when the frame was read.
+                Err(SocketModeError::Decode { .. }) => {}
+                Err(e) => error!("{}", e),
+            }
+            Flow::Continue(None)
+        })
+        .await
+    }
+
+    /// Like [`Self::run`], but drives the connection in a background task and yields events,
+    /// connection state changes, and frames that failed to decode as a stream. The stream ends
+    /// with an error if Slack disables the link, and the background task stops once the stream
+    /// is dropped. Envelopes are acked with an empty payload as soon as they are read, since the
+    /// consumer may be arbitrarily far behind; use [`Self::run`] to choose the ack payload. See
+    /// [`BufferPolicy`] for what happens when the consumer can't keep up.
+    pub fn events(self) -> impl Stream<Item = Result<SocketModeEvent, SocketModeError>> {
+        let (tx, rx) = async_std::channel::bounded(self.event_buffer_size.max(1));
+        // Only `DropOldest` needs its own receiver; holding one under `Block` would keep the
+        // channel open after the consumer leaves, and a full buffer would then block forever.
+        let overflow = (self.buffer_policy == BufferPolicy::DropOldest).then(|| rx.clone());
+        async_std::task::spawn(async move {
+            let (sender, overflow) = (&tx, overflow.as_ref());
+            let emit = |item| async move {
+                let sent = match overflow {
+                    Some(overflow) => send_dropping_oldest(sender, overflow, item),
+                    None => sender.send(item).await.is_ok(),
+                };
+                if sent {
+                    Flow::Continue(None)
+                } else {
+                    Flow::Stop
+                }
+            };
+            if let Err(e) = self.run_with(AckMode::OnReceipt, emit).await {
+                emit(Err(e)).await;
+            }
+        });
+        rx
+    }
+
+    /// Connection loop shared by [`Self::run`] and [`Self::events`]. `emit` returns
+    /// [`Flow::Stop`] once the consumer is gone, which stops the loop.
+    async fn run_with<E, Fut>(&self, mode: AckMode, emit: E) -> Result<(), SocketModeError>
+    where
+        E: Fn(Result<SocketModeEvent, SocketModeError>) -> Fut,
+        Fut: Future<Output = Flow>,
+    {
+        let mut attempt = 0;
+        // Connections in a row that ended in an error rather than a close or disconnect.
+        let mut failures = 0;
+        loop {
+            let delay = match self.connect().await {
+                Ok(stream) => {
+                    attempt = 0;
+                    if let Flow::Stop = emit(Ok(SocketModeEvent::Connected)).await {
+                        return Ok(());
+                    }
+                    let end = self.serve_connection(stream, mode, &emit).await;
+                    if end.is_ok() {
+                        failures = 0;
+                    }
+                    let reason = match &end {
+                        Ok(ConnectionEnd::Stopped) => return Ok(()),
+                        Ok(ConnectionEnd::Disconnect(reason)) => Some(reason.clone()),
+                        Ok(ConnectionEnd::Closed) | Err(_) => None,
+                    };
+                    if let Flow::Stop = emit(Ok(SocketModeEvent::Disconnected { reason })).await {
+                        return Ok(());
+                    }
+                    match end {
+                        Ok(ConnectionEnd::Disconnect(DisconnectReason::RefreshRequested)) => {
+                            Duration::ZERO
+                        }
+                        Ok(ConnectionEnd::Disconnect(DisconnectReason::TooManyConnections)) => {
+                            if self.retire_from_cluster() {
+                                warn!(
+                                    "Too many Socket Mode connections; closing this one for good"
+                                );
+                                return Ok(());
+                            }
+                            // Jitter only the second half, so no connection comes back
+                            // sooner than Slack is likely to accept it.
+                            let delay = TOO_MANY_CONNECTIONS_DELAY / 2
+                                + self.jitter(TOO_MANY_CONNECTIONS_DELAY / 2);
+                            warn!(
+                                "Too many Socket Mode connections; waiting {:?} before reconnecting",
+                                delay
+                            );
+                            delay
+                        }
+                        Ok(ConnectionEnd::Disconnect(DisconnectReason::LinkDisabled)) => {
+                            return Err(SocketModeError::LinkDisabled)
+                        }
+                        Ok(ConnectionEnd::Disconnect(DisconnectReason::Other(reason))) => {
+                            warn!("Unknown disconnect reason: {}", reason);
+                            self.jitter(INITIAL_RECONNECT_DELAY)
+                        }
+                        // A Slack-side hiccup drops every bot's connection at once, so these
+                        // need jitter as much as failed connects do.
+                        Ok(_) => self.jitter(INITIAL_RECONNECT_DELAY),
+                        Err(SocketModeError::Closed { code, reason })
+                            if !is_recoverable_close(code) =>
+                        {
+                            return Err(SocketModeError::Closed { code, reason })
+                        }
+                        Err(e) => {
+                            failures += 1;
+                            error!("Connection lost: {}", e);
+                            self.jitter(reconnect_delay(failures))
+                        }
+                    }
+                }
+                Err(e) => {
+                    attempt += 1;
+                    error!("Failed to connect (attempt {}): {}", attempt, e);
+                    self.jitter(reconnect_delay(attempt))
+                }
+            };
+            if let Flow::Stop = emit(Ok(SocketModeEvent::Reconnecting {
+                attempt: attempt + 1,
+            }))
+            .await
+            {
+                return Ok(());
+            }
+            if self.shutdown.is_cancelled() {
+                return Ok(());
+            }
+            warn!("Reconnecting to Socket Mode in {:?}", delay);
+            self.metrics.record_ws_reconnect();
+            let sleep = std::pin::pin!(async_std::task::sleep(delay));
+            let shutdown = std::pin::pin!(self.shutdown.cancelled());
+            if let Either::Right(_) = futures_util::future::select(sleep, shutdown).await {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Drops this connection from its [`SocketModeCluster`], unless it's the last one left.
+    fn retire_from_cluster(&self) -> bool {
+        self.live_connections.as_ref().is_some_and(|live| {
+            live.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| {
+                (n > 1).then(|| n - 1)
+            })
+            .is_ok()
+        })
+    }
+
+    /// Slack redelivers envelopes that aren't acked within 3 seconds, so slow acks are logged.
+    /// `received` is when the envelope's frame was read, so the latency includes the handler
+    /// under [`AckMode::AfterHandler`].
+    async fn send_ack(
+        &self,
+        stream: &mut impl WsTransport,
+        ack: &SocketModeAcknowledgeMessage<'_>,
+        received: Instant,
+    ) -> Result<(), SocketModeError> {
+        stream
+            .send(tungstenite::Message::Text(
+                serde_json::to_string(ack).expect("Failed to serialize ack message"),
+            ))
+            .await?;
+        let elapsed = received.elapsed();
+        self.metrics.record_ack(elapsed);
+        if elapsed > self.ack_warning_threshold {
+            warn!(
+                "Acked envelope {} after {:?}; Slack may redeliver it",
+                ack.envelope_id, elapsed
+            );
+        }
+        Ok(())
+    }
+
+    /// Full jitter: a random delay up to `delay`, so a fleet of bots doesn't reconnect in lockstep
+    /// after a Slack-wide outage or connection reset.
+    fn jitter(&self, delay: Duration) -> Duration {
+        self.rng
+            .lock()
+            .expect("rng lock poisoned")
+            .gen_range(Duration::ZERO..=delay)
+    }
+
+    /// Logs frames this crate can't handle, throttled so schema drift on Slack's side doesn't
+    /// flood the logs.
+    fn warn_unhandled(&self, key: &str, message: std::fmt::Arguments<'_>) {
+        let suppressed = self
+            .unknown_frames
+            .lock()
+            .expect("log throttle poisoned")
+            .check(key);
+        match suppressed {
+            Some(0) => warn!("{}", message),
+            Some(n) => warn!("{} ({} similar suppressed)", message, n),
+            None => {}
+        }
+    }
+
+    /// Records the envelope and reports whether it was already dispatched.
+    fn is_duplicate(&self, envelope_id: &str) -> bool {
+        let duplicate = !self
+            .dedup
+            .lock()
+            .expect("dedup lock poisoned")
+            .insert(envelope_id);
+        if duplicate {
+            debug!("Skipping duplicate envelope {}", envelope_id);
+        }
+        duplicate
+    }
+
+    /// Acks an envelope and hands its item to `emit`. Under [`AckMode::AfterHandler`] the ack
+    /// waits for `emit` and carries the payload it returns, unless `ack` already has one.
+    /// Returns whether to keep reading.
+    async fn dispatch<E, Fut>(
+        &self,
+        stream: &mut impl WsTransport,
+        mode: AckMode,
+        emit: &E,
+        mut ack: SocketModeAcknowledgeMessage<'_>,
+        received: Instant,
+        item: Result<SocketModeEvent, SocketModeError>,
+    ) -> Result<bool, SocketModeError>
+    where
+        E: Fn(Result<SocketModeEvent, SocketModeError>) -> Fut,
+        Fut: Future<Output = Flow>,
+    {
+        if mode == AckMode::OnReceipt || ack.payload.is_some() {
+            self.send_ack(stream, &ack, received).await?;
+            return Ok(matches!(emit(item).await, Flow::Continue(_)));
+        }
+        let keep_going = match emit(item).await {
+            Flow::Continue(payload) => {
+                ack.payload = payload;
+                true
+            }
+            Flow::Stop => false,
+        };
+        self.send_ack(stream, &ack, received).await?;
+        Ok(keep_going)
+    }
+
+    async fn serve_connection<E, Fut>(
+        &self,
+        mut stream: impl WsTransport,
+        mode: AckMode,
+        emit: &E,
+    ) -> Result<ConnectionEnd, SocketModeError>
+    where
+        E: Fn(Result<SocketModeEvent, SocketModeError>) -> Fut,
+        Fut: Future<Output = Flow>,
+    {
+        loop {
+            let next = {
+                let frame =
+                    std::pin::pin!(async_std::future::timeout(self.idle_timeout, stream.next()));
+                let shutdown = std::pin::pin!(self.shutdown.cancelled());
+                match futures_util::future::select(frame, shutdown).await {
+                    Either::Left((frame, _)) => Some(frame),
+                    Either::Right(_) => None,
+                }
+            };
+            let m = match next {
+                Some(Ok(Some(m))) => m,
+                Some(Ok(None)) => return Ok(ConnectionEnd::Closed),
+                // A half-open TCP connection never errors, it just goes quiet.
+                Some(Err(_)) => return Err(SocketModeError::IdleTimeout(self.idle_timeout)),
+                None => {
+                    info!("Shutting down Socket Mode connection");
+                    close_gracefully(stream).await?;
+                    return Ok(ConnectionEnd::Stopped);
+                }
+            };
+            let received = Instant::now();
+            let keep_going = match m? {
+                tungstenite::Message::Text(t) => {
+                    match serde_json::from_str(&t).and_then(|raw: serde_json::Value| {
+                        <SocketModeMessage as Deserialize>::deserialize(&raw)
+                            .map(|message| (message, raw))
+                    }) {
+                        Ok((SocketModeMessage::Hello { .. }, _)) => {
+                            debug!("Hello: {}", t);
+                            true
+                        }
+                        Ok((
+                            SocketModeMessage::Disconnect {
+                                reason: DisconnectReason::Warning,
+                            },
+                            _,
+                        )) => {
+                            warn!("Disconnect warning: {}", t);
+                            true
+                        }
+                        Ok((SocketModeMessage::Disconnect { reason }, _)) => {
+                            info!("Disconnect request: {:?}", reason);
+                            return Ok(ConnectionEnd::Disconnect(reason));
+                        }
+                        Ok((
+                            SocketModeMessage::EventsApi {
+                                envelope_id,
+                                payload,
+                                retry_attempt,
+                                retry_reason,
+                            },
+                            raw,
+                        )) => {
+                            info!("Events API Message: {}", t);
+                            let typed = EventKind::from_events_api(envelope_id.clone(), payload);
+                            let ack = SocketModeAcknowledgeMessage {
+                                envelope_id: &envelope_id,
+                                payload: typed.required_ack(),
+                            };
+                            if self.is_duplicate(&envelope_id) {
+                                self.send_ack(&mut stream, &ack, received).await?;
+                                continue;
+                            }
+                            self.metrics.record_event("events_api");
+                            let event = &raw["payload"]["event"];
+                            if let (Some(callback), Some("tokens_revoked")) =
+                                (&self.token_refresh_callback, event["type"].as_str())
+                            {
+                                match RevokedTokens::deserialize(&event["tokens"]) {
+                                    Ok(tokens) => callback(&tokens),
+                                    Err(e) => error!("Failed to parse tokens_revoked event: {}", e),
+                                }
+                            }
+                            let event = ParsedEvent {
+                                typed,
+                                raw,
+                                retry_num: (retry_attempt > 0).then_some(retry_attempt),
+                                retry_reason: (!retry_reason.is_empty()).then_some(retry_reason),
+                            };
+                            let item = Ok(SocketModeEvent::Event(event));
+                            self.dispatch(&mut stream, mode, emit, ack, received, item)
+                                .await?
+                        }
+                        Ok((
+                            SocketModeMessage::SlashCommands {
+                                envelope_id,
+                                payload,
+                            },
+                            raw,
+                        )) => {
+                            info!("Slash command: {}", t);
+                            let ack = SocketModeAcknowledgeMessage {
+                                envelope_id: &envelope_id,
+                                payload: None,
+                            };
+                            if self.is_duplicate(&envelope_id) {
+                                self.send_ack(&mut stream, &ack, received).await?;
+                                continue;
+                            }
+                            self.metrics.record_event("slash_commands");
+                            let item = match SlashCommandPayload::deserialize(&payload) {
+                                Ok(payload) => Ok(SocketModeEvent::Event(ParsedEvent {
+                                    typed: EventKind::SlashCommand {
+                                        envelope_id: envelope_id.clone(),
+                                        payload: Box::new(payload),
+                                    },
+                                    raw,
+                                    retry_num: None,
+                                    retry_reason: None,
+                                })),
+                                Err(source) => {
+                                    self.warn_unhandled(
+                                        "decode:slash_commands",
+                                        format_args!(
+                                            "Failed to decode slash command: {}: {}",
+                                            source, t
+                                        ),
+                                    );
+                                    Err(SocketModeError::Decode {
+                                        raw: t.clone(),
+                                        source,
+                                    })
+                                }
+                            };
+                            self.dispatch(&mut stream, mode, emit, ack, received, item)
+                                .await?
+                        }
+                        Ok((SocketModeMessage::Unknown { type_name, raw }, _)) => {
+                            self.warn_unhandled(
+                                &format!("unknown:{}", type_name),
+                                format_args!("Unknown {} message: {}", type_name, t),
+                            );
+                            let event = ParsedEvent::unknown(type_name, raw);
+                            let EventKind::Unknown {
+                                envelope_id: Some(envelope_id),
+                                type_name,
+                            } = &event.typed
+                            else {
+                                let item = Ok(SocketModeEvent::Event(event));
+                                if let Flow::Stop = emit(item).await {
+                                    return Ok(ConnectionEnd::Stopped);
+                                }
+                                continue;
+                            };
+                            let envelope_id = envelope_id.clone();
+                            let ack = SocketModeAcknowledgeMessage {
+                                envelope_id: &envelope_id,
+                                payload: None,
+                            };
+                            if self.is_duplicate(&envelope_id) {
+                                self.send_ack(&mut stream, &ack, received).await?;
+                                continue;
+                            }
+                            self.metrics.record_event(type_name);
+                            let item = Ok(SocketModeEvent::Event(event));
+                            self.dispatch(&mut stream, mode, emit, ack, received, item)
+                                .await?
+                        }
+                        Err(source) => {
+                            self.warn_unhandled(
+                                "decode:text",
+                                format_args!("Failed to decode text frame: {}: {}", source, t),
+                            );
+                            let item = Err(SocketModeError::Decode {
+                                raw: t.clone(),
+                                source,
+                            });
+                            matches!(emit(item).await, Flow::Continue(_))
+                        }
+                    }
+                }
+                tungstenite::Message::Ping(bytes) => {
+                    debug!("ping: {:?}", bytes);
+                    true
+                }
+                tungstenite::Message::Close(frame) => {
+                    let (code, reason) = frame
+                        .map(|frame| (frame.code, frame.reason.into_owned()))
+                        .unwrap_or((CloseCode::Status, String::new()));
+                    // Slack closes with 1000 after a `disconnect` message or when it recycles
+                    // the connection; anything else means something went wrong, and `run_with`
+                    // backs off or gives up depending on the code.
+                    return match code {
+                        CloseCode::Normal | CloseCode::Away => {
+                            info!("Server closed the connection ({}): {}", code, reason);
+                            Ok(ConnectionEnd::Closed)
+                        }
+                        _ => Err(SocketModeError::Closed {
+                            code: code.into(),
+                            reason,
+                        }),
+                    };
+                }
+                other => {
+                    let kind = match other {
+                        tungstenite::Message::Binary(_) => "binary",
+                        tungstenite::Message::Pong(_) => "pong",
+                        _ => "raw",
+                    };
+                    self.warn_unhandled(kind, format_args!("Unknown {} frame", kind));
+                    true
+                }
+            };
+            if !keep_going {
+                return Ok(ConnectionEnd::Stopped);
+            }
+        }
+    }
+}
+
+/// When envelopes are acked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AckMode {
+    /// As soon as they are read, with the payload they require or an empty one.
+    OnReceipt,
+    /// Once `emit` returns, with the payload it returned.
+    AfterHandler,
+}
+
+/// What the consumer of an item wants the connection loop to do next.
+enum Flow {
+    /// Keep reading, acking the item's envelope (if any) with this payload under
+    /// [`AckMode::AfterHandler`].
+    Continue(Option<AckPayload>),
+    Stop,
+}
+
+enum ConnectionEnd {
+    Disconnect(DisconnectReason),
+    Closed,
+    Stopped,
+}
+
+#[derive(Clone)]
+pub struct SocketModeClientBuilder {
+    app_level_token: String,
+    proxy: Option<url::Url>,
+    tls_connector: Option<async_tls::TlsConnector>,
+    idle_timeout: Duration,
+    ack_warning_threshold: Duration,
+    token_refresh_callback: Option<TokenRefreshCallback>,
+    metrics: Option<Arc<dyn Metrics>>,
+    dedup_cache_size: usize,
+    event_buffer_size: usize,
+    buffer_policy: BufferPolicy,
+    user_agent: String,
+}
+
+impl SocketModeClientBuilder {
+    pub fn new(app_level_token: impl Into<String>) -> Self {
+        Self {
+            app_level_token: app_level_token.into(),
+            proxy: None,
+            tls_connector: None,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            ack_warning_threshold: DEFAULT_ACK_WARNING_THRESHOLD,
+            token_refresh_callback: None,
+            metrics: None,
+            dedup_cache_size: DEFAULT_DEDUP_CACHE_SIZE,
+            event_buffer_size: DEFAULT_EVENT_BUFFER_SIZE,
+            buffer_policy: BufferPolicy::default(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+        }
+    }
+
+    /// Tunnels the WebSocket through an HTTP CONNECT proxy and routes `apps.connections.open` through it.
+    pub fn proxy(mut self, proxy: url::Url) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Overrides the TLS connector, e.g. to trust a corporate CA bundle or present a client certificate.
+    pub fn tls_connector(mut self, tls_connector: async_tls::TlsConnector) -> Self {
+        self.tls_connector = Some(tls_connector);
+        self
+    }
+
+    /// Treats the connection as dead and reconnects if no frame (pings included) arrives within `timeout`.
+    pub fn idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Logs a warning when an envelope is acked later than `threshold` after it was read.
+    /// [`SocketModeClient::run`] acks once the handler returns, so this flags slow handlers;
+    /// with [`SocketModeClient::events`] it flags a read loop held up by a full buffer.
+    /// Slack redelivers after 3 seconds, so the default is 2.5 seconds.
+    pub fn ack_warning_threshold(mut self, threshold: Duration) -> Self {
+        self.ack_warning_threshold = threshold;
+        self
+    }
+
+    pub fn metrics(mut self, metrics: Arc<dyn Metrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// How many recent envelope IDs to remember for duplicate detection. `0` disables it.
+    pub fn dedup_cache_size(mut self, size: usize) -> Self {
+        self.dedup_cache_size = size;
+        self
+    }
+
+    /// How many items [`SocketModeClient::events`] buffers for a slow consumer. At least 1;
+    /// defaults to 64.
+    pub fn event_buffer_size(mut self, size: usize) -> Self {
+        self.event_buffer_size = size;
+        self
+    }
+
+    pub fn buffer_policy(mut self, policy: BufferPolicy) -> Self {
+        self.buffer_policy = policy;
+        self
+    }
+
+    /// Replaces [`DEFAULT_USER_AGENT`] on `apps.connections.open` and the WebSocket handshake.
+    /// Fails with [`SocketModeError::InvalidUserAgent`] unless it is printable ASCII.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Result<Self, SocketModeError> {
+        let user_agent = user_agent.into();
+        if !is_valid_user_agent(&user_agent) {
+            return Err(SocketModeError::InvalidUserAgent(user_agent));
+        }
+        self.user_agent = user_agent;
+        Ok(self)
+    }
+
+    /// Called when a `tokens_revoked` event arrives, typically to fetch a new token and hand it
+    /// to [`SlackClient::set_token`](crate::SlackClient::set_token). The event is still dispatched to the handler afterwards.
+    pub fn token_refresh_callback(
+        mut self,
+        callback: impl Fn(&RevokedTokens) + Send + Sync + 'static,
+    ) -> Self {
+        self.token_refresh_callback = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn build(self) -> SocketModeClient {
+        let dedup = Arc::new(Mutex::new(EnvelopeDeduplicator::new(self.dedup_cache_size)));
+        self.build_shared(dedup, CancellationToken::default(), None)
+    }
+
+    fn build_shared(
+        self,
+        dedup: Arc<Mutex<EnvelopeDeduplicator>>,
+        shutdown: CancellationToken,
+        live_connections: Option<Arc<AtomicUsize>>,
+    ) -> SocketModeClient {
+        SocketModeClient {
+            app_level_token: self.app_level_token,
+            http: http_client(self.proxy.as_ref(), &self.user_agent),
+            proxy: self.proxy,
+            tls_connector: self.tls_connector.unwrap_or_default(),
+            idle_timeout: self.idle_timeout,
+            ack_warning_threshold: self.ack_warning_threshold,
+            token_refresh_callback: self.token_refresh_callback,
+            metrics: self.metrics.unwrap_or_else(|| Arc::new(NoopMetrics)),
+            shutdown,
+            dedup,
+            rng: Mutex::new(StdRng::from_entropy()),
+            event_buffer_size: self.event_buffer_size,
+            buffer_policy: self.buffer_policy,
+            live_connections,
+            unknown_frames: Mutex::default(),
+            user_agent: self.user_agent,
+        }
+    }
+}
+
+/// Pushes `item` without waiting, evicting the oldest buffered item through `overflow` (our own
+/// handle on the receiving end) while the buffer is full. Returns `false` once the consumer is gone.
+fn send_dropping_oldest<T>(
+    tx: &async_std::channel::Sender<T>,
+    overflow: &async_std::channel::Receiver<T>,
+    mut item: T,
+) -> bool {
+    loop {
+        // `overflow` keeps the channel open, so count receivers to notice the consumer leaving.
+        if tx.receiver_count() <= 1 {
+            return false;
+        }
+        match tx.try_send(item) {
+            Ok(()) => return true,
+            Err(async_std::channel::TrySendError::Closed(_)) => return false,
+            Err(async_std::channel::TrySendError::Full(rejected)) => {
+                if overflow.try_recv().is_ok() {
+                    warn!("Event buffer full; dropped the oldest buffered event");
+                }
+                item = rejected;
+            }
+        }
+    }
+}
+
+/// Keeps several Socket Mode connections open for the same app, as Slack recommends, so events
+/// keep flowing while one of them reconnects. Slack may deliver an envelope on more than one
+/// connection; the connections share one [`EnvelopeDeduplicator`], so each event is dispatched once.
+pub struct SocketModeCluster {
+    builder: SocketModeClientBuilder,
+    connections: usize,
+    shutdown: CancellationToken,
+}
+
+impl SocketModeCluster {
+    pub fn new(builder: SocketModeClientBuilder) -> Self {
+        Self {
+            builder,
+            connections: DEFAULT_CLUSTER_CONNECTIONS,
+            shutdown: CancellationToken::default(),
+        }
+    }
+
+    pub fn connections(mut self, connections: usize) -> Self {
+        self.connections = connections;
+        self
+    }
+
+    /// Shuts down every connection in the cluster.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            token: self.shutdown.clone(),
+        }
+    }
+
+    /// Merges the event streams of all connections. Each connection reconnects on its own, and
+    /// the stream ends once every connection has stopped. A connection that Slack disconnects
+    /// with `too_many_connections` stops instead of reconnecting, as long as another one remains.
+    pub fn events(self) -> impl Stream<Item = Result<SocketModeEvent, SocketModeError>> {
+        futures_util::stream::select_all(
+            self.build_clients()
+                .into_iter()
+                .map(SocketModeClient::events),
+        )
+    }
+
+    /// Like [`SocketModeClient::run`], but over all connections of the cluster, each acking
+    /// the envelopes it receives. Returns once every connection has stopped, or as soon as one
+    /// fails for good.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn run<F, Fut, A>(self, handler: F) -> Result<(), SocketModeError>
+    where
+        F: Fn(ParsedEvent) -> Fut,
+        Fut: Future<Output = Result<A, SlackError>>,
+        A: IntoAck,
+    {
+        let handler = &handler;
+        let clients = self.build_clients();
+        futures_util::future::try_join_all(clients.iter().map(|client| client.run(handler)))
+            .await?;
+        Ok(())
+    }
+
+    fn build_clients(&self) -> Vec<SocketModeClient> {
+        let dedup = Arc::new(Mutex::new(EnvelopeDeduplicator::new(
+            self.builder.dedup_cache_size,
+        )));
+        let live = Arc::new(AtomicUsize::new(self.connections));
+        (0..self.connections)
+            .map(|_| {
+                self.builder.clone().build_shared(
+                    dedup.clone(),
+                    self.shutdown.clone(),
+                    Some(live.clone()),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Runs one Socket Mode connection per workspace, each in its own task.
+#[derive(Default)]
+pub struct MultiWorkspaceSocketModeClient {
+    clients: Vec<(String, SocketModeClient)>,
+}
+
+impl MultiWorkspaceSocketModeClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// One connection per workspace in `registry`, made by `connect` from the team ID. The
+    /// team ID passed to the `run` handler can then be looked up in the same registry.
+    pub fn from_registry(
+        registry: &WorkspaceRegistry,
+        mut connect: impl FnMut(&str) -> SocketModeClient,
+    ) -> Self {
+        registry.team_ids().fold(Self::new(), |multi, team_id| {
+            multi.add_workspace(team_id, connect(team_id))
+        })
+    }
+
+    pub fn add_workspace(mut self, team_id: impl Into<String>, client: SocketModeClient) -> Self {
+        self.clients.push((team_id.into(), client));
+        self
+    }
+
+    /// Dispatches every workspace's events to `handler` along with the team ID they came from.
+    /// Returns once every connection has stopped. If one fails for good, the others are shut
+    /// down and waited for before its error is returned.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn run<F, Fut, A>(self, handler: F) -> Result<(), SocketModeError>
+    where
+        F: Fn(String, ParsedEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<A, SlackError>> + Send,
+        A: IntoAck,
+    {
+        let handler = Arc::new(handler);
+        let shutdowns: Vec<_> = self
+            .clients
+            .iter()
+            .map(|(_, client)| client.shutdown_handle())
+            .collect();
+        let mut tasks: Vec<_> = self
+            .clients
+            .into_iter()
+            .map(|(team_id, client)| {
+                let handler = handler.clone();
+                async_std::task::spawn(async move {
+                    client.run(|event| handler(team_id.clone(), event)).await
+                })
+            })
+            .collect();
+        let mut first_error = None;
+        while !tasks.is_empty() {
+            let (result, _, rest) = futures_util::future::select_all(tasks).await;
+            tasks = rest;
+            if let Err(e) = result {
+                if first_error.is_none() {
+                    shutdowns.iter().for_each(ShutdownHandle::shutdown);
+                    first_error = Some(e);
+                } else {
+                    error!("Socket Mode connection failed while shutting down: {}", e);
+                }
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_and_application_close_codes_are_not_recoverable() {
+        assert!(is_recoverable_close(1006));
+        assert!(is_recoverable_close(1011));
+        assert!(!is_recoverable_close(1008));
+        assert!(!is_recoverable_close(4000));
+        assert!(!is_recoverable_close(4999));
+    }
+
+    #[test]
+    fn unknown_envelope_keeps_raw_message_and_envelope_id() {
+        let raw = serde_json::json!({
+            "type": "interactive",
+            "envelope_id": "d2a9ac8c-c3a0-4d04-8fe4-dd1e2d0a7a9e",
+            "payload": { "type": "view_submission", "view": { "id": "V123" } },
+            "accepts_response_payload": true,
+        });
+        let Ok(SocketModeMessage::Unknown { type_name, raw }) =
+            <SocketModeMessage as Deserialize>::deserialize(&raw)
+        else {
+            panic!("not parsed as an unknown message");
+        };
+        let event = ParsedEvent::unknown(type_name, raw);
+        assert!(matches!(
+            &event.typed,
+            EventKind::Unknown { envelope_id: Some(id), type_name }
+                if id == "d2a9ac8c-c3a0-4d04-8fe4-dd1e2d0a7a9e" && type_name == "interactive"
+        ));
+        assert_eq!(event.raw["payload"]["view"]["id"], "V123");
+    }
+
+    #[test]
+    fn url_verification_ack_carries_challenge() {
+        let raw = serde_json::json!({
+            "type": "events_api",
+            "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf",
+            "payload": {
+                "token": "Jhj5dZrVaK7ZwHHjRyZWjbDl",
+                "challenge": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+                "type": "url_verification",
+            },
+        });
+        let Ok(SocketModeMessage::EventsApi {
+            envelope_id,
+            payload,
+            ..
+        }) = <SocketModeMessage as Deserialize>::deserialize(&raw)
+        else {
+            panic!("not parsed as an events_api envelope");
+        };
+        let typed = EventKind::from_events_api(envelope_id.clone(), payload);
+        assert!(matches!(
+            &typed,
+            EventKind::UrlVerification { challenge }
+                if challenge == "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P"
+        ));
+        let ack = SocketModeAcknowledgeMessage {
+            envelope_id: &envelope_id,
+            payload: typed.required_ack(),
+        };
+        assert_eq!(
+            serde_json::to_value(&ack).unwrap(),
+            serde_json::json!({
+                "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf",
+                "payload": "3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P",
+            })
+        );
+    }
+
+    #[test]
+    fn workflow_step_execute_event_is_typed() {
+        let payload = serde_json::json!({
+            "type": "event_callback",
+            "event": {
+                "type": "workflow_step_execute",
+                "callback_id": "send_report",
+                "workflow_step": {
+                    "workflow_step_execute_id": "1036669284371.19498874",
+                    "workflow_id": "1036669284370",
+                    "workflow_instance_id": "1034946726548",
+                    "step_id": "2f8d1cd7-5eb0-42c2-8d39-b1fbd3fb8d4e",
+                    "inputs": { "channel": { "value": "C123" } },
+                    "outputs": [],
+                },
+                "event_ts": "1643290847.766536",
+            },
+        });
+        assert!(matches!(
+            EventKind::from_events_api("e1".to_owned(), payload),
+            EventKind::WorkflowStepExecute { envelope_id, event }
+                if envelope_id == "e1"
+                    && event.callback_id == "send_report"
+                    && event.workflow_step.workflow_step_execute_id == "1036669284371.19498874"
+        ));
+    }
+
+    #[test]
+    fn log_throttle_reports_suppressed_count_once_per_interval() {
+        let mut throttle = LogThrottle::default();
+        let start = Instant::now();
+        assert_eq!(throttle.check_at("unknown:a", start), Some(0));
+        assert_eq!(throttle.check_at("unknown:a", start), None);
+        assert_eq!(
+            throttle.check_at("unknown:a", start + UNKNOWN_FRAME_LOG_INTERVAL / 2),
+            None
+        );
+        // Other keys are throttled separately.
+        assert_eq!(throttle.check_at("unknown:b", start), Some(0));
+        let later = start + UNKNOWN_FRAME_LOG_INTERVAL;
+        assert_eq!(throttle.check_at("unknown:a", later), Some(2));
+        assert_eq!(throttle.check_at("unknown:a", later), None);
+    }
+
+    #[test]
+    fn deduplicator_evicts_least_recently_seen_envelope() {
+        let mut dedup = EnvelopeDeduplicator::new(2);
+        assert!(dedup.insert("a"));
+        assert!(dedup.insert("b"));
+        assert!(!dedup.insert("a"));
+        // `a` was just seen again, so `b` is the one evicted.
+        assert!(dedup.insert("c"));
+        assert!(!dedup.insert("a"));
+        assert!(!dedup.insert("c"));
+        assert!(dedup.insert("b"));
+    }
+
+    #[test]
+    fn deduplicator_with_zero_capacity_lets_everything_through() {
+        let mut dedup = EnvelopeDeduplicator::new(0);
+        assert!(dedup.insert("a"));
+        assert!(dedup.insert("a"));
+    }
+
+    /// Replays `incoming` frames, then ends the stream, and records the frames sent back.
+    #[derive(Default)]
+    struct MockSocket {
+        incoming: VecDeque<tungstenite::Message>,
+        sent: Vec<tungstenite::Message>,
+    }
+
+    impl Stream for MockSocket {
+        type Item = Result<tungstenite::Message, tungstenite::Error>;
+
+        fn poll_next(
+            mut self: std::pin::Pin<&mut Self>,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Option<Self::Item>> {
+            std::task::Poll::Ready(self.incoming.pop_front().map(Ok))
+        }
+    }
+
+    impl futures_util::Sink<tungstenite::Message> for MockSocket {
+        type Error = tungstenite::Error;
+
+        fn poll_ready(
+            self: std::pin::Pin<&mut Self>,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn start_send(
+            mut self: std::pin::Pin<&mut Self>,
+            item: tungstenite::Message,
+        ) -> Result<(), Self::Error> {
+            self.sent.push(item);
+            Ok(())
+        }
+
+        fn poll_flush(
+            self: std::pin::Pin<&mut Self>,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(
+            self: std::pin::Pin<&mut Self>,
+            _: &mut std::task::Context<'_>,
+        ) -> std::task::Poll<Result<(), Self::Error>> {
+            std::task::Poll::Ready(Ok(()))
+        }
+    }
+
+    #[test]
+    fn duplicate_envelope_is_acked_but_not_dispatched() {
+        let envelope = serde_json::json!({
+            "type": "events_api",
+            "envelope_id": "e1",
+            "payload": { "type": "event_callback", "event": { "type": "reaction_added" } },
+        })
+        .to_string();
+        let mut socket = MockSocket {
+            incoming: [envelope.clone(), envelope]
+                .into_iter()
+                .map(tungstenite::Message::Text)
+                .collect(),
+            ..MockSocket::default()
+        };
+        let client = SocketModeClient::builder("xapp-test").build();
+        let dispatched = AtomicUsize::new(0);
+        let emit = |item: Result<SocketModeEvent, SocketModeError>| {
+            if let Ok(SocketModeEvent::Event(_)) = item {
+                dispatched.fetch_add(1, Ordering::SeqCst);
+            }
+            async { Flow::Continue(None) }
+        };
+        let end = async_std::task::block_on(client.serve_connection(
+            &mut socket,
+            AckMode::AfterHandler,
+            &emit,
+        ));
+        assert!(matches!(end, Ok(ConnectionEnd::Closed)));
+        assert_eq!(dispatched.load(Ordering::SeqCst), 1);
+        let ack = tungstenite::Message::Text(r#"{"envelope_id":"e1"}"#.to_owned());
+        assert_eq!(socket.sent, vec![ack.clone(), ack]);
+    }
+}